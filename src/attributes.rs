@@ -0,0 +1,201 @@
+//! Attribute and damage type types
+
+use errors::UnknownVariant;
+use languages::{self, Language};
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The primary attributes a job can scale with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum Attribute {
+    Strength,
+    Dexterity,
+    Vitality,
+    Intelligence,
+    Mind,
+}
+
+impl Attribute {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [Attribute; 5] = Attribute::VARIANTS;
+
+    const VARIANTS: [Attribute; 5] = [
+        Attribute::Strength,
+        Attribute::Dexterity,
+        Attribute::Vitality,
+        Attribute::Intelligence,
+        Attribute::Mind,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Attribute::Strength => "Strength",
+            Attribute::Dexterity => "Dexterity",
+            Attribute::Vitality => "Vitality",
+            Attribute::Intelligence => "Intelligence",
+            Attribute::Mind => "Mind",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.as_str()
+    }
+
+    /// Returns the name of this attribute in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            Attribute::Strength => ["Strength", "STR", "Stärke", "Force", "力量", "力量", "힘"],
+            Attribute::Dexterity => [
+                "Dexterity",
+                "DEX",
+                "Geschick",
+                "Dextérité",
+                "灵巧",
+                "靈巧",
+                "민첩성",
+            ],
+            Attribute::Vitality => [
+                "Vitality",
+                "VIT",
+                "Konstitution",
+                "Vitalité",
+                "耐力",
+                "耐力",
+                "활력",
+            ],
+            Attribute::Intelligence => [
+                "Intelligence",
+                "INT",
+                "Intelligenz",
+                "Intelligence",
+                "智力",
+                "智力",
+                "지능",
+            ],
+            Attribute::Mind => ["Mind", "MND", "Willenskraft", "Esprit", "精神", "精神", "정신력"],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns the three-letter abbreviation of this attribute (e.g. "STR").
+    pub fn abbreviation(&self) -> &'static str {
+        match *self {
+            Attribute::Strength => "STR",
+            Attribute::Dexterity => "DEX",
+            Attribute::Vitality => "VIT",
+            Attribute::Intelligence => "INT",
+            Attribute::Mind => "MND",
+        }
+    }
+
+    /// Parses a string `s` as the name of an attribute in one of the given client languages.
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        let variants = &Attribute::VARIANTS;
+        languages::find_localized(s, languages, variants, Attribute::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("Attribute", s.into()))
+    }
+}
+
+impl FromStr for Attribute {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts the name of the variant and its abbreviation (e.g. "STR"). The name in every
+    /// client language is accepted as well (see [`Attribute::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let attribute = match s.to_lowercase().as_str() {
+            "strength" | "str" => Attribute::Strength,
+            "dexterity" | "dex" => Attribute::Dexterity,
+            "vitality" | "vit" => Attribute::Vitality,
+            "intelligence" | "int" => Attribute::Intelligence,
+            "mind" | "mnd" => Attribute::Mind,
+            _ => return Attribute::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(attribute)
+    }
+}
+
+impl Display for Attribute {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The types of damage a job deals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum DamageType {
+    Physical,
+    Magical,
+}
+
+impl DamageType {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [DamageType; 2] = DamageType::VARIANTS;
+
+    const VARIANTS: [DamageType; 2] = [DamageType::Physical, DamageType::Magical];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            DamageType::Physical => "Physical",
+            DamageType::Magical => "Magical",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.as_str()
+    }
+
+    /// Returns the name of this damage type in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            DamageType::Physical => ["Physical", "物理", "Physisch", "Physique", "物理", "物理", "물리"],
+            DamageType::Magical => ["Magical", "魔法", "Magisch", "Magique", "魔法", "魔法", "마법"],
+        };
+
+        names[language as usize]
+    }
+
+    /// Parses a string `s` as the name of a damage type in one of the given client languages.
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        let variants = &DamageType::VARIANTS;
+        languages::find_localized(s, languages, variants, DamageType::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("DamageType", s.into()))
+    }
+}
+
+impl FromStr for DamageType {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// The name in every client language is accepted as well (see [`DamageType::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let damage_type = match s.to_lowercase().as_str() {
+            "physical" => DamageType::Physical,
+            "magical" | "magic" => DamageType::Magical,
+            _ => return DamageType::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(damage_type)
+    }
+}
+
+impl Display for DamageType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,617 @@
+//! Eorzea Time and calendar types
+
+use errors::{InvalidNameday, UnknownVariant};
+#[cfg(feature = "guardians")]
+use guardians::Guardian;
+use languages::{self, Language};
+
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_MINUTE: u64 = 60;
+const SECONDS_PER_BELL: u64 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_SUN: u64 = 24 * SECONDS_PER_BELL;
+const SECONDS_PER_MOON: u64 = 32 * SECONDS_PER_SUN;
+const SECONDS_PER_YEAR: u64 = 12 * SECONDS_PER_MOON;
+
+/// A point in Eorzea Time, the in-game clock.
+///
+/// Eorzea Time runs 3600/175 (about 20.57) times as fast as real time and shares its epoch with
+/// Unix time. An Eorzean day (a sun) has 24 bells of 60 minutes, a moon has 32 suns and a year has
+/// 12 moons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub struct EorzeaTime {
+    seconds: u64,
+}
+
+impl EorzeaTime {
+    /// Creates a time from its components, or returns `None` if one of them is out of range.
+    ///
+    /// `year` and `sun` count from 1, `bell` and `minute` from 0.
+    pub fn new(year: u64, moon: Moon, sun: u8, bell: u8, minute: u8) -> Option<EorzeaTime> {
+        if year == 0 || sun == 0 || sun > 32 || bell > 23 || minute > 59 {
+            return None;
+        }
+
+        let seconds = (year - 1)
+            .checked_mul(SECONDS_PER_YEAR)?
+            .checked_add(u64::from(moon.number() - 1) * SECONDS_PER_MOON)?
+            .checked_add(u64::from(sun - 1) * SECONDS_PER_SUN)?
+            .checked_add(u64::from(bell) * SECONDS_PER_BELL)?
+            .checked_add(u64::from(minute) * SECONDS_PER_MINUTE)?;
+
+        Some(EorzeaTime { seconds })
+    }
+
+    /// Creates a time from the number of Eorzean seconds since the epoch.
+    pub fn from_timestamp(seconds: u64) -> EorzeaTime {
+        EorzeaTime { seconds }
+    }
+
+    /// Returns the Eorzea Time at the given Unix timestamp, in seconds.
+    pub fn from_unix(seconds: u64) -> EorzeaTime {
+        EorzeaTime::from_unix_millis(seconds.saturating_mul(1000))
+    }
+
+    /// Returns the Eorzea Time at the given Unix timestamp, in milliseconds.
+    pub fn from_unix_millis(millis: u64) -> EorzeaTime {
+        let seconds = u128::from(millis) * 3600 / 175 / 1000;
+
+        EorzeaTime {
+            seconds: seconds as u64,
+        }
+    }
+
+    /// Returns the current Eorzea Time.
+    pub fn now() -> EorzeaTime {
+        let since_epoch = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        EorzeaTime::from_unix_millis(since_epoch.as_millis() as u64)
+    }
+
+    /// Returns the number of Eorzean seconds since the epoch.
+    pub fn timestamp(&self) -> u64 {
+        self.seconds
+    }
+
+    /// Returns the Unix timestamp, in seconds, at which this Eorzea Time begins.
+    pub fn to_unix(&self) -> u64 {
+        (self.unix_millis() / 1000) as u64
+    }
+
+    /// Returns the Unix timestamp, in milliseconds, at which this Eorzea Time begins, or `None` if
+    /// it does not fit in a `u64` (for times over 11 billion Eorzean years after the epoch).
+    pub fn to_unix_millis(&self) -> Option<u64> {
+        u64::try_from(self.unix_millis()).ok()
+    }
+
+    fn unix_millis(&self) -> u128 {
+        let millis = u128::from(self.seconds) * 175 * 1000;
+        millis / 3600 + u128::from(millis % 3600 != 0)
+    }
+
+    /// Returns the year, counting from 1.
+    pub fn year(&self) -> u64 {
+        self.seconds / SECONDS_PER_YEAR + 1
+    }
+
+    /// Returns the moon (month).
+    pub fn moon(&self) -> Moon {
+        let number = (self.seconds / SECONDS_PER_MOON % 12) as u8 + 1;
+
+        Moon::from_number(number).expect("moon numbers are always in range")
+    }
+
+    /// Returns the sun (day of the moon), from 1 to 32.
+    pub fn sun(&self) -> u8 {
+        (self.seconds / SECONDS_PER_SUN % 32) as u8 + 1
+    }
+
+    /// Returns the bell (hour), from 0 to 23.
+    pub fn bell(&self) -> u8 {
+        (self.seconds / SECONDS_PER_BELL % 24) as u8
+    }
+
+    /// Returns the minute, from 0 to 59.
+    pub fn minute(&self) -> u8 {
+        (self.seconds / SECONDS_PER_MINUTE % 60) as u8
+    }
+
+    /// Returns the second, from 0 to 59.
+    pub fn second(&self) -> u8 {
+        (self.seconds % 60) as u8
+    }
+
+    /// Returns the [`Nameday`] of a character born at this time.
+    pub fn nameday(&self) -> Nameday {
+        Nameday {
+            moon: self.moon(),
+            sun: self.sun(),
+        }
+    }
+
+    /// Formats the date and time as the game does in the given client language (e.g. "5th Sun of
+    /// the 1st Astral Moon, 12:34" or "第一星历月 5日 12:34").
+    pub fn format_in(&self, language: Language) -> String {
+        let date = format_date(self.moon(), self.sun(), language);
+        let separator = match language {
+            Language::English | Language::German | Language::French => ",",
+            _ => "",
+        };
+
+        format!("{}{} {:02}:{:02}", date, separator, self.bell(), self.minute())
+    }
+}
+
+impl Display for EorzeaTime {
+    /// Formats the date and time in English (see [`EorzeaTime::format_in`]).
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.format_in(Language::English))
+    }
+}
+
+/// Formats the `sun` of the `moon` as the game does in the given client language.
+fn format_date(moon: Moon, sun: u8, language: Language) -> String {
+    let moon = moon.name_in(language);
+
+    match language {
+        Language::English => format!("{}{} Sun of the {}", sun, ordinal_suffix(sun), moon),
+        Language::German => format!("{}. Sonne im {}", sun, moon),
+        Language::French => {
+            let suffix = if sun == 1 { "er" } else { "e" };
+            format!("{}{} soleil de la {}", sun, suffix, moon)
+        }
+        Language::Korean => format!("{} {}일", moon, sun),
+        Language::Japanese | Language::ChineseSimplified | Language::ChineseTraditional => {
+            format!("{} {}日", moon, sun)
+        }
+    }
+}
+
+/// Returns the English ordinal suffix of `n` (e.g. "st" for 1, "nd" for 22).
+fn ordinal_suffix(n: u8) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// The halves of an Eorzean moon pair.
+///
+/// Each of the six pairs of moons in a year is made of an Astral moon followed by an Umbral moon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum MoonHalf {
+    Astral,
+    Umbral,
+}
+
+impl MoonHalf {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [MoonHalf; 2] = [MoonHalf::Astral, MoonHalf::Umbral];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            MoonHalf::Astral => "Astral",
+            MoonHalf::Umbral => "Umbral",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.as_str()
+    }
+
+    /// Returns the name of this half in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            MoonHalf::Astral => ["Astral", "星暦", "Astral", "Astral", "星历", "星曆", "성력"],
+            MoonHalf::Umbral => ["Umbral", "霊暦", "Umbral", "Ombral", "灵历", "靈曆", "영력"],
+        };
+
+        names[language as usize]
+    }
+}
+
+impl FromStr for MoonHalf {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let half = match s.to_lowercase().as_str() {
+            "astral" => MoonHalf::Astral,
+            "umbral" => MoonHalf::Umbral,
+            _ => return Err(UnknownVariant("MoonHalf", s.into())),
+        };
+
+        Ok(half)
+    }
+}
+
+impl Display for MoonHalf {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The twelve moons (months) of the Eorzean calendar, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum Moon {
+    FirstAstral,
+    FirstUmbral,
+    SecondAstral,
+    SecondUmbral,
+    ThirdAstral,
+    ThirdUmbral,
+    FourthAstral,
+    FourthUmbral,
+    FifthAstral,
+    FifthUmbral,
+    SixthAstral,
+    SixthUmbral,
+}
+
+impl Moon {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [Moon; 12] = Moon::VARIANTS;
+
+    const VARIANTS: [Moon; 12] = [
+        Moon::FirstAstral,
+        Moon::FirstUmbral,
+        Moon::SecondAstral,
+        Moon::SecondUmbral,
+        Moon::ThirdAstral,
+        Moon::ThirdUmbral,
+        Moon::FourthAstral,
+        Moon::FourthUmbral,
+        Moon::FifthAstral,
+        Moon::FifthUmbral,
+        Moon::SixthAstral,
+        Moon::SixthUmbral,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Moon::FirstAstral => "FirstAstral",
+            Moon::FirstUmbral => "FirstUmbral",
+            Moon::SecondAstral => "SecondAstral",
+            Moon::SecondUmbral => "SecondUmbral",
+            Moon::ThirdAstral => "ThirdAstral",
+            Moon::ThirdUmbral => "ThirdUmbral",
+            Moon::FourthAstral => "FourthAstral",
+            Moon::FourthUmbral => "FourthUmbral",
+            Moon::FifthAstral => "FifthAstral",
+            Moon::FifthUmbral => "FifthUmbral",
+            Moon::SixthAstral => "SixthAstral",
+            Moon::SixthUmbral => "SixthUmbral",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name_in(Language::English)
+    }
+
+    /// Returns the name of this moon in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            Moon::FirstAstral => [
+                "1st Astral Moon",
+                "第一星暦月",
+                "1. Astralmond",
+                "1re lune astrale",
+                "第一星历月",
+                "第一星曆月",
+                "제1성력월",
+            ],
+            Moon::FirstUmbral => [
+                "1st Umbral Moon",
+                "第一霊暦月",
+                "1. Umbralmond",
+                "1re lune ombrale",
+                "第一灵历月",
+                "第一靈曆月",
+                "제1영력월",
+            ],
+            Moon::SecondAstral => [
+                "2nd Astral Moon",
+                "第二星暦月",
+                "2. Astralmond",
+                "2e lune astrale",
+                "第二星历月",
+                "第二星曆月",
+                "제2성력월",
+            ],
+            Moon::SecondUmbral => [
+                "2nd Umbral Moon",
+                "第二霊暦月",
+                "2. Umbralmond",
+                "2e lune ombrale",
+                "第二灵历月",
+                "第二靈曆月",
+                "제2영력월",
+            ],
+            Moon::ThirdAstral => [
+                "3rd Astral Moon",
+                "第三星暦月",
+                "3. Astralmond",
+                "3e lune astrale",
+                "第三星历月",
+                "第三星曆月",
+                "제3성력월",
+            ],
+            Moon::ThirdUmbral => [
+                "3rd Umbral Moon",
+                "第三霊暦月",
+                "3. Umbralmond",
+                "3e lune ombrale",
+                "第三灵历月",
+                "第三靈曆月",
+                "제3영력월",
+            ],
+            Moon::FourthAstral => [
+                "4th Astral Moon",
+                "第四星暦月",
+                "4. Astralmond",
+                "4e lune astrale",
+                "第四星历月",
+                "第四星曆月",
+                "제4성력월",
+            ],
+            Moon::FourthUmbral => [
+                "4th Umbral Moon",
+                "第四霊暦月",
+                "4. Umbralmond",
+                "4e lune ombrale",
+                "第四灵历月",
+                "第四靈曆月",
+                "제4영력월",
+            ],
+            Moon::FifthAstral => [
+                "5th Astral Moon",
+                "第五星暦月",
+                "5. Astralmond",
+                "5e lune astrale",
+                "第五星历月",
+                "第五星曆月",
+                "제5성력월",
+            ],
+            Moon::FifthUmbral => [
+                "5th Umbral Moon",
+                "第五霊暦月",
+                "5. Umbralmond",
+                "5e lune ombrale",
+                "第五灵历月",
+                "第五靈曆月",
+                "제5영력월",
+            ],
+            Moon::SixthAstral => [
+                "6th Astral Moon",
+                "第六星暦月",
+                "6. Astralmond",
+                "6e lune astrale",
+                "第六星历月",
+                "第六星曆月",
+                "제6성력월",
+            ],
+            Moon::SixthUmbral => [
+                "6th Umbral Moon",
+                "第六霊暦月",
+                "6. Umbralmond",
+                "6e lune ombrale",
+                "第六灵历月",
+                "第六靈曆月",
+                "제6영력월",
+            ],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns the number of this moon in the year, from 1 to 12.
+    pub fn number(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    /// Returns the moon with the given number in the year, from 1 to 12.
+    pub fn from_number(number: u8) -> Option<Moon> {
+        match number {
+            1..=12 => Some(Moon::VARIANTS[usize::from(number - 1)]),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of the pair this moon belongs to, from 1 to 6 (e.g. 3 for the 3rd Umbral
+    /// Moon).
+    pub fn pair(&self) -> u8 {
+        self.number() / 2 + self.number() % 2
+    }
+
+    /// Returns whether this is the Astral or the Umbral moon of its pair.
+    pub fn half(&self) -> MoonHalf {
+        if self.number() % 2 == 1 {
+            MoonHalf::Astral
+        } else {
+            MoonHalf::Umbral
+        }
+    }
+
+    /// Returns the [`Guardian`] ruling this moon.
+    #[cfg(feature = "guardians")]
+    pub fn guardian(&self) -> Guardian {
+        match *self {
+            Moon::FirstAstral => Guardian::Halone,
+            Moon::FirstUmbral => Guardian::Menphina,
+            Moon::SecondAstral => Guardian::Thaliak,
+            Moon::SecondUmbral => Guardian::Nymeia,
+            Moon::ThirdAstral => Guardian::Llymlaen,
+            Moon::ThirdUmbral => Guardian::Oschon,
+            Moon::FourthAstral => Guardian::Byregot,
+            Moon::FourthUmbral => Guardian::Rhalgr,
+            Moon::FifthAstral => Guardian::Azeyma,
+            Moon::FifthUmbral => Guardian::NaldThal,
+            Moon::SixthAstral => Guardian::Nophica,
+            Moon::SixthUmbral => Guardian::Althyk,
+        }
+    }
+
+    /// Parses a string `s` as the name of a moon in one of the given client languages.
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(s, languages, &Moon::VARIANTS, Moon::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("Moon", s.into()))
+    }
+}
+
+impl FromStr for Moon {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts the name of the variant and the name with the ordinal spelled out (e.g. "First
+    /// Astral Moon"). The name in every client language is accepted as well (see
+    /// [`Moon::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let moon = match s.to_lowercase().as_str() {
+            "firstastral" | "first astral moon" => Moon::FirstAstral,
+            "firstumbral" | "first umbral moon" => Moon::FirstUmbral,
+            "secondastral" | "second astral moon" => Moon::SecondAstral,
+            "secondumbral" | "second umbral moon" => Moon::SecondUmbral,
+            "thirdastral" | "third astral moon" => Moon::ThirdAstral,
+            "thirdumbral" | "third umbral moon" => Moon::ThirdUmbral,
+            "fourthastral" | "fourth astral moon" => Moon::FourthAstral,
+            "fourthumbral" | "fourth umbral moon" => Moon::FourthUmbral,
+            "fifthastral" | "fifth astral moon" => Moon::FifthAstral,
+            "fifthumbral" | "fifth umbral moon" => Moon::FifthUmbral,
+            "sixthastral" | "sixth astral moon" => Moon::SixthAstral,
+            "sixthumbral" | "sixth umbral moon" => Moon::SixthUmbral,
+            _ => return Moon::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(moon)
+    }
+}
+
+impl Display for Moon {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A character's nameday (birthday): a sun of a moon of the Eorzean calendar.
+///
+/// The nameday chosen at character creation determines the character's [`Guardian`], as each
+/// moon is ruled by one of the Twelve.
+///
+/// With feature `with_serde`, deserializing a nameday checks that its sun is between 1 and 32.
+///
+/// [`Guardian`]: ::guardians::Guardian
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "with_serde", serde(try_from = "NamedayFields"))]
+pub struct Nameday {
+    moon: Moon,
+    sun: u8,
+}
+
+impl Nameday {
+    /// Creates a nameday, or returns `None` if `sun` is not between 1 and 32.
+    pub fn new(moon: Moon, sun: u8) -> Option<Nameday> {
+        match sun {
+            1..=32 => Some(Nameday { moon, sun }),
+            _ => None,
+        }
+    }
+
+    /// Returns the moon of this nameday.
+    pub fn moon(&self) -> Moon {
+        self.moon
+    }
+
+    /// Returns the sun of this nameday, from 1 to 32.
+    pub fn sun(&self) -> u8 {
+        self.sun
+    }
+
+    /// Returns the [`Guardian`] of characters born on this nameday.
+    #[cfg(feature = "guardians")]
+    pub fn guardian(&self) -> Guardian {
+        self.moon.guardian()
+    }
+
+    /// Formats this nameday as the game does in the given client language (e.g. "5th Sun of the
+    /// 1st Astral Moon" or "第一星历月 5日").
+    pub fn format_in(&self, language: Language) -> String {
+        format_date(self.moon, self.sun, language)
+    }
+}
+
+impl FromStr for Nameday {
+    type Err = InvalidNameday;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts namedays as the game writes them in every client language (e.g. "1st Sun of the
+    /// 1st Astral Moon" or "第一星历月 1日"), with or without "第" before the sun (e.g. "第一星历月
+    /// 第1日").
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || InvalidNameday(s.into());
+        let lower = s.trim().to_lowercase();
+
+        let (sun, moon) = [" sun of the ", ". sonne im ", " soleil de la "]
+            .iter()
+            .find_map(|separator| lower.split_once(separator))
+            .map(|(sun, moon)| (sun.trim_end_matches(char::is_alphabetic), moon))
+            .or_else(|| {
+                let end = lower.find(['月', '월'])?;
+                let (moon, sun) = lower.split_at(end + lower[end..].chars().next()?.len_utf8());
+                let sun = sun.trim().trim_start_matches('第').trim_end_matches(['日', '일']);
+                Some((sun, moon))
+            })
+            .ok_or_else(err)?;
+
+        let moon = moon.trim().parse().map_err(|_| err())?;
+        let sun = sun.trim().parse().map_err(|_| err())?;
+
+        Nameday::new(moon, sun).ok_or_else(err)
+    }
+}
+
+impl Display for Nameday {
+    /// Formats this nameday in English (see [`Nameday::format_in`]).
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.format_in(Language::English))
+    }
+}
+
+/// The serialized form of a [`Nameday`], validated when deserializing.
+#[cfg(feature = "with_serde")]
+#[derive(Deserialize)]
+struct NamedayFields {
+    moon: Moon,
+    sun: u8,
+}
+
+#[cfg(feature = "with_serde")]
+impl TryFrom<NamedayFields> for Nameday {
+    type Error = InvalidNameday;
+
+    fn try_from(fields: NamedayFields) -> Result<Self, Self::Error> {
+        Nameday::new(fields.moon, fields.sun)
+            .ok_or_else(|| InvalidNameday(format_date(fields.moon, fields.sun, Language::English)))
+    }
+}
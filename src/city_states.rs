@@ -0,0 +1,138 @@
+//! City-state types
+
+use errors::UnknownVariant;
+#[cfg(feature = "combat_jobs")]
+use jobs::Class;
+use languages::{self, Language};
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The city-states of Eorzea a new character can start in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum CityState {
+    LimsaLominsa,
+    Gridania,
+    UlDah,
+}
+
+impl CityState {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [CityState; 3] = CityState::VARIANTS;
+
+    const VARIANTS: [CityState; 3] = [CityState::LimsaLominsa, CityState::Gridania, CityState::UlDah];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            CityState::LimsaLominsa => "LimsaLominsa",
+            CityState::Gridania => "Gridania",
+            CityState::UlDah => "UlDah",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            CityState::LimsaLominsa => "Limsa Lominsa",
+            CityState::Gridania => "Gridania",
+            CityState::UlDah => "Ul'dah",
+        }
+    }
+
+    /// Returns the official name of this city-state in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            CityState::LimsaLominsa => [
+                "Limsa Lominsa",
+                "リムサ・ロミンサ",
+                "Limsa Lominsa",
+                "Limsa Lominsa",
+                "利姆萨·罗敏萨",
+                "利姆薩·羅敏薩",
+                "림사 로민사",
+            ],
+            CityState::Gridania => [
+                "Gridania",
+                "グリダニア",
+                "Gridania",
+                "Gridania",
+                "格里达尼亚",
+                "格里達尼亞",
+                "그리다니아",
+            ],
+            CityState::UlDah => ["Ul'dah", "ウルダハ", "Ul'dah", "Ul'dah", "乌尔达哈", "烏爾達哈", "울다하"],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns common community abbreviations of this city-state's name in the given client
+    /// language (e.g. "海都" for Limsa Lominsa in Simplified Chinese).
+    pub fn abbreviations_in(&self, language: Language) -> &'static [&'static str] {
+        match language {
+            Language::ChineseSimplified | Language::ChineseTraditional => match *self {
+                CityState::LimsaLominsa => &["海都"],
+                CityState::Gridania => &["森都"],
+                CityState::UlDah => &["沙都"],
+            },
+            _ => &[],
+        }
+    }
+
+    /// Returns the [`Class`]es a new character can start as in this city-state, whose guilds are
+    /// in this city-state.
+    ///
+    /// Rogue is not included: its guild is in Limsa Lominsa, but it cannot be chosen at character
+    /// creation.
+    #[cfg(feature = "combat_jobs")]
+    pub fn starting_classes(&self) -> &'static [Class] {
+        match *self {
+            CityState::LimsaLominsa => &[Class::Marauder, Class::Arcanist],
+            CityState::Gridania => &[Class::Lancer, Class::Archer, Class::Conjurer],
+            CityState::UlDah => &[Class::Gladiator, Class::Pugilist, Class::Thaumaturge],
+        }
+    }
+
+    /// Parses a string `s` as the official name of a city-state in one of the given client
+    /// languages or one of its community abbreviations (see [`CityState::abbreviations_in`]).
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(
+            s,
+            languages,
+            &CityState::VARIANTS,
+            CityState::name_in,
+            CityState::abbreviations_in,
+        )
+        .ok_or_else(|| UnknownVariant("CityState", s.into()))
+    }
+}
+
+impl FromStr for CityState {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts the name of the variant and the name with spaces and apostrophes (e.g.
+    /// "Ul'dah"). The official name in every client language is accepted as well (see
+    /// [`CityState::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let city_state = match s.to_lowercase().as_str() {
+            "limsalominsa" | "limsa lominsa" => CityState::LimsaLominsa,
+            "gridania" => CityState::Gridania,
+            "uldah" | "ul'dah" => CityState::UlDah,
+            _ => return CityState::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(city_state)
+    }
+}
+
+impl Display for CityState {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
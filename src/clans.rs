@@ -1,6 +1,7 @@
 //! Clan types
 
 use errors::UnknownVariant;
+use languages::{self, Language};
 
 #[cfg(feature = "races")]
 use races::Race;
@@ -40,7 +41,9 @@ pub enum Clan {
 
 impl Clan {
     #[cfg(feature = "all_const")]
-    pub const ALL: [Clan; 16] = [
+    pub const ALL: [Clan; 16] = Clan::VARIANTS;
+
+    const VARIANTS: [Clan; 16] = [
         Clan::Raen,
         Clan::Xaela,
         Clan::Duskwight,
@@ -102,6 +105,110 @@ impl Clan {
         }
     }
 
+    /// Returns the official name of this clan in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            Clan::Raen => ["Raen", "アウラ・レン", "Raen", "Raen", "晨曦之民", "晨曦之民", "렌"],
+            Clan::Xaela => ["Xaela", "アウラ・ゼラ", "Xaela", "Xaela", "暮晖之民", "暮暉之民", "젤라"],
+            Clan::Duskwight => [
+                "Duskwight",
+                "シェーダー",
+                "Dunkelalb",
+                "Crépusculaire",
+                "黑影之民",
+                "黑影之民",
+                "그늘숲",
+            ],
+            Clan::Wildwood => [
+                "Wildwood",
+                "フォレスター",
+                "Erlschatten",
+                "Sylvestre",
+                "森林之民",
+                "森林之民",
+                "숲지기",
+            ],
+            Clan::Helions => ["Helions", "ヘリオン", "Helion", "Hélion", "掌握之民", "掌握之民", "맹호족"],
+            Clan::TheLost => ["The Lost", "ロスト", "Losgesagter", "Égaré", "迷失之民", "迷失之民", "떠돌이족"],
+            Clan::Highlander => [
+                "Highlander",
+                "ハイランダー",
+                "Hochländer",
+                "Hyurgoth",
+                "高地之民",
+                "高地之民",
+                "하이랜더",
+            ],
+            Clan::Midlander => [
+                "Midlander",
+                "ミッドランダー",
+                "Wiesländer",
+                "Hyurois",
+                "中原之民",
+                "中原之民",
+                "미드랜더",
+            ],
+            Clan::Dunesfolk => [
+                "Dunesfolk",
+                "デューンフォーク",
+                "Dünenbewohner",
+                "Lalafell des dunes",
+                "沙漠之民",
+                "沙漠之民",
+                "듄폭",
+            ],
+            Clan::Plainsfolk => [
+                "Plainsfolk",
+                "プレーンフォーク",
+                "Flachländer",
+                "Lalafell des plaines",
+                "平原之民",
+                "平原之民",
+                "플레인폭",
+            ],
+            Clan::KeeperOfTheMoon => [
+                "Keeper of the Moon",
+                "ムーンキーパー",
+                "Hüter des Mondes",
+                "Tribu de la lune",
+                "护月之民",
+                "護月之民",
+                "달의 수호자",
+            ],
+            Clan::SeekerOfTheSun => [
+                "Seeker of the Sun",
+                "サンシーカー",
+                "Goldjäger",
+                "Tribu du soleil",
+                "逐日之民",
+                "逐日之民",
+                "태양의 추종자",
+            ],
+            Clan::Hellsguard => [
+                "Hellsguard",
+                "ヘルズガード",
+                "Höllenwächter",
+                "Clan de l'enfer",
+                "红焰之民",
+                "紅焰之民",
+                "불꽃지킴이",
+            ],
+            Clan::SeaWolf => [
+                "Sea Wolf",
+                "ゼーヴォルフ",
+                "Seewolf",
+                "Clan de la mer",
+                "北洋之民",
+                "北洋之民",
+                "바다늑대",
+            ],
+            Clan::Rava => ["Rava", "ラヴァ・ヴィエラ", "Rava", "Rava", "密林之民", "密林之民", "라바"],
+            Clan::Veena => ["Veena", "ヴィナ・ヴィエラ", "Veena", "Veena", "山林之民", "山林之民", "비나"],
+        };
+
+        names[language as usize]
+    }
+
     #[cfg(feature = "races")]
     pub fn race(&self) -> Race {
         match *self {
@@ -115,6 +222,14 @@ impl Clan {
             Clan::Rava | Clan::Veena => Race::Viera,
         }
     }
+
+    /// Parses a string `s` as the official name of a clan in one of the given client languages.
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(s, languages, &Clan::VARIANTS, Clan::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("Clan", s.into()))
+    }
 }
 
 impl FromStr for Clan {
@@ -122,6 +237,8 @@ impl FromStr for Clan {
 
     /// Parses a string `s` to return a value of this type.
     ///
+    /// The official name in every client language is accepted as well (see [`Clan::from_str_in`]).
+    ///
     /// This is case-insensitive.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let world = match s.to_lowercase().as_str() {
@@ -141,7 +258,7 @@ impl FromStr for Clan {
             "seawolf" | "sea wolf" => Clan::SeaWolf,
             "rava" => Clan::Rava,
             "veena" => Clan::Veena,
-            _ => return Err(UnknownVariant("Clan", s.into()))
+            _ => return Clan::from_str_in(s, &Language::VARIANTS)
         };
 
         Ok(world)
@@ -1,7 +1,13 @@
 //! Data center types
 
-use errors::UnknownVariant;
+use errors::{UnknownId, UnknownVariant};
+use languages::Language;
+#[cfg(feature = "regions")]
+use regions::Region;
+#[cfg(feature = "worlds")]
+use worlds::World;
 
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
 
@@ -78,6 +84,266 @@ impl DataCenter {
         // if any variants with spaces are added, this must be changed
         self.as_str()
     }
+
+    /// Returns the name of this data center in the given client language.
+    ///
+    /// Data center names are not translated; every client shows the same name.
+    pub fn name_in(&self, _language: Language) -> &'static str {
+        self.name()
+    }
+
+    /// Returns the row ID of this data center in the game's `WorldDCGroupType` sheet.
+    pub fn id(&self) -> u16 {
+        match *self {
+            DataCenter::Aether => 4,
+            DataCenter::Chaos => 6,
+            DataCenter::Crystal => 8,
+            DataCenter::Elemental => 1,
+            DataCenter::Gaia => 2,
+            DataCenter::Light => 7,
+            DataCenter::Mana => 3,
+            DataCenter::Materia => 9,
+            DataCenter::Meteor => 10,
+            DataCenter::Primal => 5,
+            DataCenter::Dynamis => 11,
+            DataCenter::Shadow => 12,
+            DataCenter::陆行鸟 => 101,
+            DataCenter::陸行鳥 => 151,
+            DataCenter::莫古力 => 102,
+            DataCenter::猫小胖 => 103,
+            DataCenter::豆豆柴 => 104,
+            DataCenter::한국 => 201,
+        }
+    }
+
+    /// Returns the data center with the given row ID in the `WorldDCGroupType` sheet, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        let data_center = match id {
+            4 => DataCenter::Aether,
+            6 => DataCenter::Chaos,
+            8 => DataCenter::Crystal,
+            1 => DataCenter::Elemental,
+            2 => DataCenter::Gaia,
+            7 => DataCenter::Light,
+            3 => DataCenter::Mana,
+            9 => DataCenter::Materia,
+            10 => DataCenter::Meteor,
+            5 => DataCenter::Primal,
+            11 => DataCenter::Dynamis,
+            12 => DataCenter::Shadow,
+            101 => DataCenter::陆行鸟,
+            151 => DataCenter::陸行鳥,
+            102 => DataCenter::莫古力,
+            103 => DataCenter::猫小胖,
+            104 => DataCenter::豆豆柴,
+            201 => DataCenter::한국,
+            _ => return None,
+        };
+
+        Some(data_center)
+    }
+
+    /// Returns the [`Region`] this data center serves.
+    #[cfg(feature = "regions")]
+    pub fn region(&self) -> Region {
+        match *self {
+            DataCenter::Aether | DataCenter::Crystal | DataCenter::Dynamis | DataCenter::Primal => {
+                Region::NorthAmerica
+            }
+            DataCenter::Chaos | DataCenter::Light | DataCenter::Shadow => Region::Europe,
+            DataCenter::Elemental | DataCenter::Gaia | DataCenter::Mana | DataCenter::Meteor => {
+                Region::Japan
+            }
+            DataCenter::Materia => Region::Oceania,
+            DataCenter::陆行鸟 | DataCenter::莫古力 | DataCenter::猫小胖 | DataCenter::豆豆柴 => {
+                Region::China
+            }
+            DataCenter::陸行鳥 => Region::Taiwan,
+            DataCenter::한국 => Region::Korea,
+        }
+    }
+
+    /// Returns the [`World`]s on this data center, in the order the game lists them.
+    #[cfg(feature = "worlds")]
+    pub fn worlds(&self) -> &'static [World] {
+        match *self {
+            DataCenter::Aether => &[
+                World::Adamantoise,
+                World::Cactuar,
+                World::Faerie,
+                World::Gilgamesh,
+                World::Jenova,
+                World::Midgardsormr,
+                World::Sargatanas,
+                World::Siren,
+            ],
+            DataCenter::Chaos => &[
+                World::Cerberus,
+                World::Louisoix,
+                World::Moogle,
+                World::Omega,
+                World::Phantom,
+                World::Ragnarok,
+                World::Sagittarius,
+                World::Spriggan,
+            ],
+            DataCenter::Crystal => &[
+                World::Balmung,
+                World::Brynhildr,
+                World::Coeurl,
+                World::Diabolos,
+                World::Goblin,
+                World::Malboro,
+                World::Mateus,
+                World::Zalera,
+            ],
+            DataCenter::Elemental => &[
+                World::Aegis,
+                World::Atomos,
+                World::Carbuncle,
+                World::Garuda,
+                World::Gungnir,
+                World::Kujata,
+                World::Tonberry,
+                World::Typhon,
+            ],
+            DataCenter::Gaia => &[
+                World::Alexander,
+                World::Bahamut,
+                World::Durandal,
+                World::Fenrir,
+                World::Ifrit,
+                World::Ridill,
+                World::Tiamat,
+                World::Ultima,
+            ],
+            DataCenter::Light => &[
+                World::Alpha,
+                World::Lich,
+                World::Odin,
+                World::Phoenix,
+                World::Raiden,
+                World::Shiva,
+                World::Twintania,
+                World::Zodiark,
+            ],
+            DataCenter::Mana => &[
+                World::Anima,
+                World::Asura,
+                World::Chocobo,
+                World::Hades,
+                World::Ixion,
+                World::Masamune,
+                World::Pandaemonium,
+                World::Titan,
+            ],
+            DataCenter::Materia => &[
+                World::Bismarck,
+                World::Ravana,
+                World::Sephirot,
+                World::Sophia,
+                World::Zurvan,
+            ],
+            DataCenter::Meteor => &[
+                World::Belias,
+                World::Mandragora,
+                World::Ramuh,
+                World::Shinryu,
+                World::Unicorn,
+                World::Valefor,
+                World::Yojimbo,
+                World::Zeromus,
+            ],
+            DataCenter::Primal => &[
+                World::Behemoth,
+                World::Excalibur,
+                World::Exodus,
+                World::Famfrit,
+                World::Hyperion,
+                World::Lamia,
+                World::Leviathan,
+                World::Ultros,
+            ],
+            DataCenter::Dynamis => &[
+                World::Cuchulainn,
+                World::Golem,
+                World::Halicarnassus,
+                World::Kraken,
+                World::Maduin,
+                World::Marilith,
+                World::Rafflesia,
+                World::Seraph,
+            ],
+            DataCenter::Shadow => &[World::Innocence, World::Pixie, World::Titania, World::Tycoon],
+            DataCenter::陆行鸟 => &[
+                World::晨曦王座,
+                World::沃仙曦染,
+                World::宇宙和音,
+                World::红玉海,
+                World::萌芽池,
+                World::神意之地,
+                World::幻影群岛,
+                World::拉诺西亚,
+            ],
+            DataCenter::陸行鳥 => &[
+                World::伊弗利特,
+                World::迦樓羅,
+                World::利維坦,
+                World::鳳凰,
+                World::奧汀,
+                World::巴哈姆特,
+                World::拉姆,
+                World::泰坦,
+            ],
+            DataCenter::莫古力 => &[
+                World::拂晓之间,
+                World::龙巢神殿,
+                World::旅人栈桥,
+                World::白金幻象,
+                World::梦羽宝境,
+                World::神拳痕,
+                World::潮风亭,
+                World::白银乡,
+            ],
+            DataCenter::猫小胖 => &[
+                World::琥珀原,
+                World::柔风海湾,
+                World::海猫茶屋,
+                World::延夏,
+                World::静语庄园,
+                World::摩杜纳,
+                World::紫水栈桥,
+            ],
+            DataCenter::豆豆柴 => &[
+                World::红茶川,
+                World::伊修加德,
+                World::太阳海岸,
+                World::银泪湖,
+                World::水晶塔,
+                World::亚马乌罗提,
+            ],
+            DataCenter::한국 => &[World::펜리르, World::카벙클, World::톤베리, World::초코보, World::모그리],
+        }
+    }
+
+    /// Returns the city this data center is physically hosted in (e.g. "Sacramento", "Tokyo").
+    pub fn location(&self) -> &'static str {
+        match *self {
+            DataCenter::Aether | DataCenter::Crystal | DataCenter::Dynamis | DataCenter::Primal => {
+                "Sacramento"
+            }
+            DataCenter::Chaos | DataCenter::Light | DataCenter::Shadow => "London",
+            DataCenter::Elemental | DataCenter::Gaia | DataCenter::Mana | DataCenter::Meteor => {
+                "Tokyo"
+            }
+            DataCenter::Materia => "Sydney",
+            DataCenter::陆行鸟 | DataCenter::莫古力 | DataCenter::猫小胖 | DataCenter::豆豆柴 => {
+                "Shanghai"
+            }
+            DataCenter::陸行鳥 => "Taipei",
+            DataCenter::한국 => "Seoul",
+        }
+    }
 }
 
 impl FromStr for DataCenter {
@@ -110,6 +376,14 @@ impl FromStr for DataCenter {
     }
 }
 
+impl TryFrom<u16> for DataCenter {
+    type Error = UnknownId;
+
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        DataCenter::from_id(id).ok_or(UnknownId("DataCenter", id))
+    }
+}
+
 impl Display for DataCenter {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         write!(f, "{}", self.name())
@@ -22,3 +22,97 @@ impl Display for UnknownVariant {
         write!(f, "unknown variant {} for type {}", self.1, self.0)
     }
 }
+
+/// An error representing an unknown game ID of any `enum`.
+///
+/// `(enum name, unknown ID)`, e.g. `("World", 0)`
+///
+/// This is generally encountered when using [`TryFrom<u16>`] on any `enum` in this crate.
+///
+/// [`TryFrom<u16>`]: ::std::convert::TryFrom
+#[derive(Debug)]
+pub struct UnknownId(
+    /// The `enum` name (e.g. `"World"`)
+    pub &'static str,
+    /// The unknown ID given
+    pub u16,
+);
+
+impl Display for UnknownId {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "unknown id {} for type {}", self.1, self.0)
+    }
+}
+
+/// An error representing a string that is not a valid [`GameVersion`].
+///
+/// [`GameVersion`]: ::versions::GameVersion
+#[derive(Debug)]
+pub struct InvalidGameVersion(
+    /// The invalid input given
+    pub String,
+);
+
+impl Display for InvalidGameVersion {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "invalid game version {}", self.0)
+    }
+}
+
+/// An error representing a number that is not a valid [`Level`], either at all or for a given job
+/// and patch.
+///
+/// [`Level`]: ::levels::Level
+#[cfg(feature = "levels")]
+#[derive(Debug)]
+pub struct InvalidLevel(
+    /// The invalid level given
+    pub u8,
+);
+
+#[cfg(feature = "levels")]
+impl Display for InvalidLevel {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "invalid level {}", self.0)
+    }
+}
+
+/// An error representing a string that is not a valid [`Nameday`].
+///
+/// [`Nameday`]: ::calendar::Nameday
+#[cfg(feature = "calendar")]
+#[derive(Debug)]
+pub struct InvalidNameday(
+    /// The invalid input given
+    pub String,
+);
+
+#[cfg(feature = "calendar")]
+impl Display for InvalidNameday {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "invalid nameday {}", self.0)
+    }
+}
+
+/// An error representing a [`Party`] with more members than its kind of party has room for.
+///
+/// `(members, room)`, e.g. `(9, 8)` for a full party of 9
+///
+/// This is generally encountered when deserializing a [`Party`].
+///
+/// [`Party`]: ::parties::Party
+#[cfg(feature = "parties")]
+#[derive(Debug)]
+pub struct PartyTooLarge(
+    /// The number of members given
+    pub usize,
+    /// The number of members the party has room for
+    pub usize,
+);
+
+#[cfg(feature = "parties")]
+impl Display for PartyTooLarge {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "party of {} members has room for {} only", self.0, self.1)
+    }
+}
@@ -0,0 +1,197 @@
+//! Expansion types
+
+use errors::UnknownVariant;
+use languages::{self, Language};
+use versions::GameVersion;
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The expansions of the game, in release order.
+///
+/// A Realm Reborn, the relaunched base game, is counted as the first expansion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum Expansion {
+    ARealmReborn,
+    Heavensward,
+    Stormblood,
+    Shadowbringers,
+    Endwalker,
+    Dawntrail,
+}
+
+impl Expansion {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [Expansion; 6] = Expansion::VARIANTS;
+
+    const VARIANTS: [Expansion; 6] = [
+        Expansion::ARealmReborn,
+        Expansion::Heavensward,
+        Expansion::Stormblood,
+        Expansion::Shadowbringers,
+        Expansion::Endwalker,
+        Expansion::Dawntrail,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Expansion::ARealmReborn => "ARealmReborn",
+            Expansion::Heavensward => "Heavensward",
+            Expansion::Stormblood => "Stormblood",
+            Expansion::Shadowbringers => "Shadowbringers",
+            Expansion::Endwalker => "Endwalker",
+            Expansion::Dawntrail => "Dawntrail",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Expansion::ARealmReborn => "A Realm Reborn",
+            Expansion::Heavensward => "Heavensward",
+            Expansion::Stormblood => "Stormblood",
+            Expansion::Shadowbringers => "Shadowbringers",
+            Expansion::Endwalker => "Endwalker",
+            Expansion::Dawntrail => "Dawntrail",
+        }
+    }
+
+    /// Returns the official name of this expansion in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            Expansion::ARealmReborn => [
+                "A Realm Reborn",
+                "新生エオルゼア",
+                "A Realm Reborn",
+                "A Realm Reborn",
+                "重生之境",
+                "重生之境",
+                "신생 에오르제아",
+            ],
+            Expansion::Heavensward => [
+                "Heavensward",
+                "蒼天のイシュガルド",
+                "Heavensward",
+                "Heavensward",
+                "苍穹之禁城",
+                "蒼穹之禁城",
+                "창천의 이슈가르드",
+            ],
+            Expansion::Stormblood => [
+                "Stormblood",
+                "紅蓮のリベレーター",
+                "Stormblood",
+                "Stormblood",
+                "红莲之狂潮",
+                "紅蓮之狂潮",
+                "홍련의 해방자",
+            ],
+            Expansion::Shadowbringers => [
+                "Shadowbringers",
+                "漆黒のヴィランズ",
+                "Shadowbringers",
+                "Shadowbringers",
+                "暗影之逆焰",
+                "暗影之逆焰",
+                "칠흑의 반역자",
+            ],
+            Expansion::Endwalker => [
+                "Endwalker",
+                "暁月のフィナーレ",
+                "Endwalker",
+                "Endwalker",
+                "晓月之终途",
+                "曉月之終途",
+                "효월의 종언",
+            ],
+            Expansion::Dawntrail => [
+                "Dawntrail",
+                "黄金のレガシー",
+                "Dawntrail",
+                "Dawntrail",
+                "金曦之遗辉",
+                "金曦之遺輝",
+                "황금의 유산",
+            ],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns the short code of this expansion (e.g. "ARR", "ShB").
+    pub fn code(&self) -> &'static str {
+        match *self {
+            Expansion::ARealmReborn => "ARR",
+            Expansion::Heavensward => "HW",
+            Expansion::Stormblood => "SB",
+            Expansion::Shadowbringers => "ShB",
+            Expansion::Endwalker => "EW",
+            Expansion::Dawntrail => "DT",
+        }
+    }
+
+    /// Returns the patch this expansion was released in (e.g. 3.0 for Heavensward).
+    pub fn version(&self) -> GameVersion {
+        GameVersion::new(*self as u8 + 2, 0)
+    }
+
+    /// Returns the maximum level of combat jobs in this expansion.
+    pub fn level_cap(&self) -> u8 {
+        50 + 10 * *self as u8
+    }
+
+    /// Returns the expansion patch `version` belongs to (e.g. Stormblood for patch 4.5).
+    ///
+    /// Patches older than 2.0 belong to A Realm Reborn.
+    pub fn of(version: GameVersion) -> Expansion {
+        Expansion::VARIANTS
+            .iter()
+            .cloned()
+            .rev()
+            .find(|expansion| expansion.version() <= version)
+            .unwrap_or(Expansion::ARealmReborn)
+    }
+
+    /// Parses a string `s` as the official name of an expansion in one of the given client
+    /// languages.
+    ///
+    /// This is case-insensitive. See [`languages`] for the forms only [`FromStr`] accepts.
+    ///
+    /// [`languages`]: ::languages
+    /// [`FromStr`]: ::std::str::FromStr
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(s, languages, &Expansion::VARIANTS, Expansion::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("Expansion", s.into()))
+    }
+}
+
+impl FromStr for Expansion {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts the name of the variant, the name with spaces between words and the short code
+    /// of each expansion (e.g. "ShB" for Shadowbringers). The official name in every client
+    /// language is accepted as well (see [`Expansion::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expansion = match s.to_lowercase().as_str() {
+            "arealmreborn" | "a realm reborn" | "arr" => Expansion::ARealmReborn,
+            "heavensward" | "hw" => Expansion::Heavensward,
+            "stormblood" | "sb" => Expansion::Stormblood,
+            "shadowbringers" | "shb" => Expansion::Shadowbringers,
+            "endwalker" | "ew" => Expansion::Endwalker,
+            "dawntrail" | "dt" => Expansion::Dawntrail,
+            _ => return Expansion::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(expansion)
+    }
+}
+
+impl Display for Expansion {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
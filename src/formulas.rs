@@ -0,0 +1,345 @@
+//! Damage and stat formulas
+//!
+//! These are the formulas worked out by the community from in-game testing, rounding down at the
+//! same steps as the game. Damage estimates ignore buffs, debuffs and the random ±5% damage
+//! variance.
+
+use attributes::Attribute;
+use jobs::{Class, ClassJob, Job};
+use levels::Level;
+use roles::{Role, SubRole};
+
+use std::convert::TryFrom;
+
+/// The modifiers the stat formulas scale with at a level cap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub struct LevelModifiers {
+    /// The base value of primary attributes and determination.
+    pub main: u32,
+    /// The base value of the other substats (critical hit, direct hit, speed and tenacity).
+    pub sub: u32,
+    /// The divisor of substats.
+    pub div: u32,
+    /// The slope of the attack power multiplier of jobs that are not tanks.
+    pub attack_power: u32,
+    /// The slope of the attack power multiplier of tanks.
+    pub tank_attack_power: u32,
+}
+
+impl LevelModifiers {
+    /// The level modifiers at each level cap, from 50 to 100.
+    pub const LEVEL_CAPS: [(u8, LevelModifiers); 6] = [
+        (50, LevelModifiers::new(202, 341, 341, 75, 75)),
+        (60, LevelModifiers::new(218, 354, 600, 100, 100)),
+        (70, LevelModifiers::new(292, 364, 900, 125, 105)),
+        (80, LevelModifiers::new(340, 380, 1300, 165, 115)),
+        (90, LevelModifiers::new(390, 400, 1900, 195, 156)),
+        (100, LevelModifiers::new(440, 420, 2780, 237, 190)),
+    ];
+
+    const fn new(
+        main: u32,
+        sub: u32,
+        div: u32,
+        attack_power: u32,
+        tank_attack_power: u32,
+    ) -> LevelModifiers {
+        LevelModifiers {
+            main,
+            sub,
+            div,
+            attack_power,
+            tank_attack_power,
+        }
+    }
+
+    /// Returns the level modifiers at `level`, or `None` if it is not a level cap.
+    pub fn at(level: Level) -> Option<LevelModifiers> {
+        LevelModifiers::LEVEL_CAPS
+            .iter()
+            .find(|&&(cap, _)| cap == level.get())
+            .map(|&(_, modifiers)| modifiers)
+    }
+}
+
+/// The stats of a character, as shown on the character window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub struct Stats {
+    /// The physical or magic damage of the weapon, whichever the job uses.
+    pub weapon_damage: u32,
+    /// The value of the job's primary attribute (see [`Job::primary_attribute`]).
+    pub main_attribute: u32,
+    /// Critical hit, raising the chance and damage of critical hits.
+    pub critical_hit: u32,
+    /// Direct hit rate, raising the chance of direct hits.
+    pub direct_hit: u32,
+    /// Determination, raising all damage and healing.
+    pub determination: u32,
+    /// Skill speed or spell speed, whichever the job uses.
+    pub speed: u32,
+    /// Tenacity, raising the damage dealt and lowering the damage taken by tanks.
+    pub tenacity: u32,
+}
+
+/// The stat formulas of a class or job at a level cap.
+///
+/// Multipliers are returned in percent or in thousandths, as the game computes them, e.g. a
+/// [`critical_hit_rate`] of `250` is a 25% chance.
+///
+/// Stats below their base value at the level count as the base value, and results too large for a
+/// `u32` saturate at `u32::MAX`.
+///
+/// With feature `with_serde`, formulas are not serializable: store the class or job and the level
+/// and call [`Formulas::new`] again instead.
+///
+/// [`critical_hit_rate`]: Formulas::critical_hit_rate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Formulas {
+    level: LevelModifiers,
+    attributes: [u32; 5],
+    primary_attribute: Attribute,
+    role: Role,
+    sub_role: SubRole,
+}
+
+impl Formulas {
+    /// Returns the formulas of a class or job at `level`, or `None` for a [`NonCombatJob`] or if
+    /// `level` is not a level cap (see [`LevelModifiers::LEVEL_CAPS`]).
+    ///
+    /// [`NonCombatJob`]: ::jobs::NonCombatJob
+    pub fn new<J: Into<ClassJob>>(class_job: J, level: Level) -> Option<Formulas> {
+        let (attributes, primary_attribute, role, sub_role) = match class_job.into() {
+            ClassJob::Job(job) => (
+                job_attribute_modifiers(job),
+                job.primary_attribute(),
+                job.role(),
+                job.sub_role(),
+            ),
+            ClassJob::Class(class) => (
+                class_attribute_modifiers(class),
+                class_primary_attribute(class),
+                class.role(),
+                class.sub_role(),
+            ),
+            #[cfg(feature = "non_combat_jobs")]
+            ClassJob::NonCombat(_) => return None,
+        };
+
+        Some(Formulas {
+            level: LevelModifiers::at(level)?,
+            attributes,
+            primary_attribute,
+            role,
+            sub_role,
+        })
+    }
+
+    /// Returns the level modifiers these formulas scale with.
+    pub fn level_modifiers(&self) -> LevelModifiers {
+        self.level
+    }
+
+    /// Returns the modifier of `attribute` for the class or job, in percent.
+    ///
+    /// Attributes grow with the level in proportion to this modifier, and the modifier of the
+    /// primary attribute scales weapon damage. The modifiers of Beastmaster are provisional: they
+    /// are the ones of Dragoon until the job's own are measured.
+    pub fn attribute_modifier(&self, attribute: Attribute) -> u32 {
+        self.attributes[attribute as usize]
+    }
+
+    /// Returns the chance of a critical hit, in thousandths.
+    pub fn critical_hit_rate(&self, critical_hit: u32) -> u32 {
+        self.scale(200, critical_hit, self.level.sub).saturating_add(50)
+    }
+
+    /// Returns the damage multiplier of a critical hit, in thousandths.
+    pub fn critical_hit_multiplier(&self, critical_hit: u32) -> u32 {
+        self.scale(200, critical_hit, self.level.sub).saturating_add(1400)
+    }
+
+    /// Returns the chance of a direct hit, in thousandths.
+    pub fn direct_hit_rate(&self, direct_hit: u32) -> u32 {
+        self.scale(550, direct_hit, self.level.sub)
+    }
+
+    /// Returns the damage multiplier of a direct hit, in thousandths.
+    pub fn direct_hit_multiplier(&self) -> u32 {
+        1250
+    }
+
+    /// Returns the damage and healing multiplier of determination, in thousandths.
+    pub fn determination_multiplier(&self, determination: u32) -> u32 {
+        self.scale(140, determination, self.level.main).saturating_add(1000)
+    }
+
+    /// Returns the damage multiplier of tenacity, in thousandths, or 1000 for jobs that are not
+    /// tanks.
+    pub fn tenacity_multiplier(&self, tenacity: u32) -> u32 {
+        if self.role == Role::Tank {
+            self.scale(112, tenacity, self.level.sub).saturating_add(1000)
+        } else {
+            1000
+        }
+    }
+
+    /// Returns the damage multiplier of the primary attribute, in percent.
+    pub fn attack_power_multiplier(&self, main_attribute: u32) -> u32 {
+        let slope = if self.role == Role::Tank {
+            self.level.tank_attack_power
+        } else {
+            self.level.attack_power
+        };
+        let main = self.level.main;
+        let multiplier = u64::from(slope) * u64::from(main_attribute.saturating_sub(main))
+            / u64::from(main);
+
+        saturate(multiplier).saturating_add(100)
+    }
+
+    /// Returns the damage multiplier of weapon damage, in percent.
+    pub fn weapon_damage_multiplier(&self, weapon_damage: u32) -> u32 {
+        let multiplier = self.level.main * self.attribute_modifier(self.primary_attribute) / 1000;
+
+        multiplier.saturating_add(weapon_damage)
+    }
+
+    /// Returns the damage multiplier of the role traits (e.g. Increased Action Damage II for
+    /// physical ranged DPS and Maim and Mend II for casters and healers), in percent.
+    pub fn trait_multiplier(&self) -> u32 {
+        match self.sub_role {
+            SubRole::PhysicalRanged => 120,
+            SubRole::MagicalRanged | SubRole::PureHealer | SubRole::BarrierHealer => 130,
+            SubRole::Tank | SubRole::Melee => 100,
+        }
+    }
+
+    /// Returns the recast time of a weaponskill or spell, in milliseconds.
+    ///
+    /// `base` is the recast time of the action at the lowest speed, in milliseconds (e.g. `2500`),
+    /// and `haste` is the percentage of haste from traits and buffs (e.g. `13` for Greased
+    /// Lightning at four stacks). Like the game, the result is rounded down to hundredths of a
+    /// second.
+    pub fn gcd(&self, base: u32, speed: u32, haste: u32) -> u32 {
+        let speed = 1000u32.saturating_sub(self.scale(130, speed, self.level.sub));
+        let gcd = u64::from(base) * u64::from(speed) / 1000;
+        let gcd = gcd * u64::from(100u32.saturating_sub(haste)) / 100;
+
+        saturate(gcd / 10 * 10)
+    }
+
+    /// Returns the damage of an action of the given potency that is neither a critical hit nor a
+    /// direct hit.
+    ///
+    /// Like the game, potency is first multiplied by attack power and determination and rounded
+    /// down, then by tenacity, weapon damage and the trait, rounding down after each. The random
+    /// ±5% variance and buffs are not applied.
+    pub fn damage(&self, potency: u32, stats: &Stats) -> u32 {
+        let damage = u64::from(potency)
+            .saturating_mul(self.attack_power_multiplier(stats.main_attribute).into())
+            .saturating_mul(self.determination_multiplier(stats.determination).into())
+            / 100
+            / 1000;
+
+        let multipliers = [
+            (self.tenacity_multiplier(stats.tenacity), 1000),
+            (self.weapon_damage_multiplier(stats.weapon_damage), 100),
+            (self.trait_multiplier(), 100),
+        ];
+
+        let mut damage = saturate(damage);
+        for &(multiplier, scale) in multipliers.iter() {
+            damage = saturate(u64::from(damage) * u64::from(multiplier) / scale);
+        }
+
+        damage
+    }
+
+    /// Returns the average damage of an action of the given potency, taking the chances of
+    /// critical and direct hits into account.
+    pub fn expected_damage(&self, potency: u32, stats: &Stats) -> f64 {
+        let critical_hit_rate = f64::from(self.critical_hit_rate(stats.critical_hit)) / 1000.0;
+        let critical_hit_bonus =
+            f64::from(self.critical_hit_multiplier(stats.critical_hit)) / 1000.0 - 1.0;
+        let direct_hit_rate = f64::from(self.direct_hit_rate(stats.direct_hit)) / 1000.0;
+        let direct_hit_bonus = f64::from(self.direct_hit_multiplier()) / 1000.0 - 1.0;
+
+        f64::from(self.damage(potency, stats))
+            * (1.0 + critical_hit_rate * critical_hit_bonus)
+            * (1.0 + direct_hit_rate * direct_hit_bonus)
+    }
+
+    /// Computes `floor(coefficient * (stat - base) / div)`, the shape of every substat formula.
+    fn scale(&self, coefficient: u32, stat: u32, base: u32) -> u32 {
+        let stat = u64::from(stat.saturating_sub(base));
+
+        saturate(u64::from(coefficient) * stat / u64::from(self.level.div))
+    }
+}
+
+/// Converts `value` to a `u32`, saturating at `u32::MAX`.
+fn saturate(value: u64) -> u32 {
+    u32::try_from(value).unwrap_or(u32::MAX)
+}
+
+/// Returns the attribute modifiers of `job`, in the order of [`Attribute`]'s variants: strength,
+/// dexterity, vitality, intelligence and mind.
+fn job_attribute_modifiers(job: Job) -> [u32; 5] {
+    match job {
+        Job::Paladin => [100, 95, 110, 60, 100],
+        Job::Warrior => [105, 95, 110, 40, 55],
+        Job::DarkKnight => [105, 95, 110, 60, 40],
+        Job::Gunbreaker => [100, 95, 110, 60, 100],
+
+        Job::WhiteMage => [55, 105, 100, 105, 115],
+        Job::Scholar => [90, 100, 100, 105, 115],
+        Job::Astrologian => [50, 100, 100, 105, 115],
+        Job::Sage => [60, 100, 100, 115, 115],
+
+        Job::Monk => [110, 105, 100, 50, 90],
+        Job::Dragoon => [115, 100, 105, 45, 65],
+        Job::Ninja => [85, 110, 100, 65, 75],
+        Job::Samurai => [112, 108, 100, 60, 50],
+        Job::Reaper => [115, 100, 105, 80, 40],
+        Job::Viper => [100, 110, 100, 65, 75],
+        // Provisional: the modifiers of Dragoon until Beastmaster's own are measured.
+        Job::Beastmaster => [115, 100, 105, 45, 65],
+
+        Job::Bard => [90, 115, 100, 85, 80],
+        Job::Machinist => [85, 115, 100, 80, 85],
+        Job::Dancer => [90, 115, 100, 85, 80],
+
+        Job::BlackMage => [45, 100, 100, 115, 75],
+        Job::Summoner => [90, 100, 100, 115, 80],
+        Job::RedMage => [55, 105, 100, 115, 110],
+        Job::Pictomancer => [85, 100, 100, 115, 80],
+        Job::BlueMage => [70, 110, 100, 115, 105],
+    }
+}
+
+/// Returns the attribute modifiers of `class`, in the same order as [`job_attribute_modifiers`].
+fn class_attribute_modifiers(class: Class) -> [u32; 5] {
+    match class {
+        Class::Gladiator => [95, 90, 100, 50, 95],
+        Class::Marauder => [100, 90, 100, 30, 50],
+        Class::Conjurer => [50, 100, 95, 100, 105],
+        Class::Pugilist => [100, 100, 95, 45, 85],
+        Class::Lancer => [105, 95, 100, 40, 60],
+        Class::Rogue => [80, 105, 95, 60, 70],
+        Class::Archer => [85, 105, 95, 80, 75],
+        Class::Thaumaturge => [40, 95, 95, 105, 70],
+        Class::Arcanist => [85, 95, 95, 105, 75],
+    }
+}
+
+/// Returns the primary attribute of `class`, which is the one of the jobs it turns into.
+fn class_primary_attribute(class: Class) -> Attribute {
+    match class {
+        Class::Gladiator | Class::Marauder | Class::Pugilist | Class::Lancer => Attribute::Strength,
+        Class::Rogue | Class::Archer => Attribute::Dexterity,
+        Class::Thaumaturge | Class::Arcanist => Attribute::Intelligence,
+        Class::Conjurer => Attribute::Mind,
+    }
+}
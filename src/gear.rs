@@ -0,0 +1,195 @@
+//! Gear category types
+
+#[cfg(feature = "attributes")]
+use attributes::Attribute;
+use errors::UnknownVariant;
+use languages::{self, Language};
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The categories of combat gear, named after the suffix of their item names (e.g. "Augmented
+/// Scaevan Coat of Fending").
+///
+/// Armor and accessories are not split the same way: the left side (head to feet) has a category
+/// per group of jobs, while some groups share accessories (see
+/// [`GearCategory::accessory_category`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum GearCategory {
+    Fending,
+    Maiming,
+    Striking,
+    Scouting,
+    Aiming,
+    Healing,
+    Casting,
+    Slaying,
+}
+
+impl GearCategory {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [GearCategory; 8] = GearCategory::VARIANTS;
+
+    const VARIANTS: [GearCategory; 8] = [
+        GearCategory::Fending,
+        GearCategory::Maiming,
+        GearCategory::Striking,
+        GearCategory::Scouting,
+        GearCategory::Aiming,
+        GearCategory::Healing,
+        GearCategory::Casting,
+        GearCategory::Slaying,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            GearCategory::Fending => "Fending",
+            GearCategory::Maiming => "Maiming",
+            GearCategory::Striking => "Striking",
+            GearCategory::Scouting => "Scouting",
+            GearCategory::Aiming => "Aiming",
+            GearCategory::Healing => "Healing",
+            GearCategory::Casting => "Casting",
+            GearCategory::Slaying => "Slaying",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.as_str()
+    }
+
+    /// Returns the name of this category in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            GearCategory::Fending => [
+                "Fending",
+                "ディフェンダー",
+                "Verteidigung",
+                "Protection",
+                "御敌",
+                "禦敵",
+                "수호",
+            ],
+            GearCategory::Maiming => [
+                "Maiming",
+                "メイム",
+                "Verstümmelung",
+                "Mutilation",
+                "制敌",
+                "制敵",
+                "제압",
+            ],
+            GearCategory::Striking => [
+                "Striking",
+                "ストライカー",
+                "Schlag",
+                "Frappe",
+                "强攻",
+                "強攻",
+                "타격",
+            ],
+            GearCategory::Scouting => [
+                "Scouting",
+                "スカウト",
+                "Spähen",
+                "Exploration",
+                "游击",
+                "遊擊",
+                "유격",
+            ],
+            GearCategory::Aiming => ["Aiming", "レンジャー", "Zielen", "Visée", "精准", "精準", "사격"],
+            GearCategory::Healing => ["Healing", "ヒーラー", "Heilung", "Soin", "治愈", "治癒", "치유"],
+            GearCategory::Casting => [
+                "Casting",
+                "キャスター",
+                "Zauberei",
+                "Incantation",
+                "咏咒",
+                "詠咒",
+                "마법",
+            ],
+            GearCategory::Slaying => [
+                "Slaying",
+                "スレイヤー",
+                "Metzelei",
+                "Massacre",
+                "强袭",
+                "強襲",
+                "학살",
+            ],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns the category of the accessories (earrings, necklace, bracelets and rings) worn with
+    /// armor of this category.
+    ///
+    /// Maiming and Striking armor share Slaying accessories, and Scouting armor shares Aiming
+    /// accessories; the other categories have their own accessories.
+    pub fn accessory_category(&self) -> GearCategory {
+        match *self {
+            GearCategory::Maiming | GearCategory::Striking => GearCategory::Slaying,
+            GearCategory::Scouting => GearCategory::Aiming,
+            category => category,
+        }
+    }
+
+    /// Returns the primary attribute found on gear of this category.
+    #[cfg(feature = "attributes")]
+    pub fn attribute(&self) -> Attribute {
+        match *self {
+            GearCategory::Fending
+            | GearCategory::Maiming
+            | GearCategory::Striking
+            | GearCategory::Slaying => Attribute::Strength,
+
+            GearCategory::Scouting | GearCategory::Aiming => Attribute::Dexterity,
+
+            GearCategory::Healing => Attribute::Mind,
+
+            GearCategory::Casting => Attribute::Intelligence,
+        }
+    }
+
+    /// Parses a string `s` as the name of a gear category in one of the given client languages.
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        let variants = &GearCategory::VARIANTS;
+        languages::find_localized(s, languages, variants, GearCategory::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("GearCategory", s.into()))
+    }
+}
+
+impl FromStr for GearCategory {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// The name in every client language is accepted as well (see [`GearCategory::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let category = match s.to_lowercase().as_str() {
+            "fending" => GearCategory::Fending,
+            "maiming" => GearCategory::Maiming,
+            "striking" => GearCategory::Striking,
+            "scouting" => GearCategory::Scouting,
+            "aiming" => GearCategory::Aiming,
+            "healing" => GearCategory::Healing,
+            "casting" => GearCategory::Casting,
+            "slaying" => GearCategory::Slaying,
+            _ => return GearCategory::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(category)
+    }
+}
+
+impl Display for GearCategory {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,85 @@
+//! Gender types
+
+use errors::UnknownVariant;
+use languages::{self, Language};
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The genders available at character creation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum Gender {
+    Male,
+    Female,
+}
+
+impl Gender {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [Gender; 2] = Gender::VARIANTS;
+
+    const VARIANTS: [Gender; 2] = [Gender::Male, Gender::Female];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Gender::Male => "Male",
+            Gender::Female => "Female",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.as_str()
+    }
+
+    /// Returns the name of this gender in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            Gender::Male => ["Male", "男性", "Männlich", "Homme", "男", "男", "남성"],
+            Gender::Female => ["Female", "女性", "Weiblich", "Femme", "女", "女", "여성"],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns the symbol of this gender (♂ or ♀), as shown on character profiles.
+    pub fn symbol(&self) -> char {
+        match *self {
+            Gender::Male => '♂',
+            Gender::Female => '♀',
+        }
+    }
+
+    /// Parses a string `s` as the name of a gender in one of the given client languages.
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(s, languages, &Gender::VARIANTS, Gender::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("Gender", s.into()))
+    }
+}
+
+impl FromStr for Gender {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts the name of the variant and the symbol of each gender (e.g. "♀"). The name in
+    /// every client language is accepted as well (see [`Gender::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let gender = match s.to_lowercase().as_str() {
+            "male" | "♂" => Gender::Male,
+            "female" | "♀" => Gender::Female,
+            _ => return Gender::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(gender)
+    }
+}
+
+impl Display for Gender {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
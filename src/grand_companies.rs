@@ -0,0 +1,509 @@
+//! Grand Company types
+
+#[cfg(feature = "city_states")]
+use city_states::CityState;
+use errors::{UnknownId, UnknownVariant};
+use languages::{self, Language};
+
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The Grand Companies of Eorzea.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum GrandCompany {
+    Maelstrom,
+    TwinAdder,
+    ImmortalFlames,
+}
+
+impl GrandCompany {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [GrandCompany; 3] = GrandCompany::VARIANTS;
+
+    const VARIANTS: [GrandCompany; 3] = [
+        GrandCompany::Maelstrom,
+        GrandCompany::TwinAdder,
+        GrandCompany::ImmortalFlames,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            GrandCompany::Maelstrom => "Maelstrom",
+            GrandCompany::TwinAdder => "TwinAdder",
+            GrandCompany::ImmortalFlames => "ImmortalFlames",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            GrandCompany::Maelstrom => "Maelstrom",
+            GrandCompany::TwinAdder => "Order of the Twin Adder",
+            GrandCompany::ImmortalFlames => "Immortal Flames",
+        }
+    }
+
+    /// Returns the official name of this Grand Company in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            GrandCompany::Maelstrom => ["Maelstrom", "黒渦団", "Mahlstrom", "Maelstrom", "黑涡团", "黑渦團", "흑와단"],
+            GrandCompany::TwinAdder => [
+                "Order of the Twin Adder",
+                "双蛇党",
+                "Bruderschaft der Morgenviper",
+                "Deux Vipères",
+                "双蛇党",
+                "雙蛇黨",
+                "쌍사당",
+            ],
+            GrandCompany::ImmortalFlames => [
+                "Immortal Flames",
+                "不滅隊",
+                "Legion der Unsterblichen",
+                "Immortels",
+                "恒辉队",
+                "恆輝隊",
+                "불멸대",
+            ],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns common community abbreviations of this Grand Company's name in the given client
+    /// language (e.g. "黑涡" for the Maelstrom in Simplified Chinese).
+    pub fn abbreviations_in(&self, language: Language) -> &'static [&'static str] {
+        match language {
+            Language::English => match *self {
+                GrandCompany::TwinAdder => &["Twin Adder"],
+                _ => &[],
+            },
+            Language::ChineseSimplified => match *self {
+                GrandCompany::Maelstrom => &["黑涡"],
+                GrandCompany::TwinAdder => &["双蛇"],
+                GrandCompany::ImmortalFlames => &["恒辉"],
+            },
+            Language::ChineseTraditional => match *self {
+                GrandCompany::Maelstrom => &["黑渦"],
+                GrandCompany::TwinAdder => &["雙蛇"],
+                GrandCompany::ImmortalFlames => &["恆輝"],
+            },
+            _ => &[],
+        }
+    }
+
+    /// Returns the row ID of this Grand Company in the game's `GrandCompany` sheet.
+    pub fn id(&self) -> u16 {
+        match *self {
+            GrandCompany::Maelstrom => 1,
+            GrandCompany::TwinAdder => 2,
+            GrandCompany::ImmortalFlames => 3,
+        }
+    }
+
+    /// Returns the Grand Company with the given row ID in the game's `GrandCompany` sheet.
+    pub fn from_id(id: u16) -> Option<Self> {
+        let grand_company = match id {
+            1 => GrandCompany::Maelstrom,
+            2 => GrandCompany::TwinAdder,
+            3 => GrandCompany::ImmortalFlames,
+            _ => return None,
+        };
+
+        Some(grand_company)
+    }
+
+    /// Returns the [`CityState`] this Grand Company is headquartered in.
+    #[cfg(feature = "city_states")]
+    pub fn city_state(&self) -> CityState {
+        match *self {
+            GrandCompany::Maelstrom => CityState::LimsaLominsa,
+            GrandCompany::TwinAdder => CityState::Gridania,
+            GrandCompany::ImmortalFlames => CityState::UlDah,
+        }
+    }
+
+    /// Returns the title of members of this Grand Company with the given rank in the given client
+    /// language (e.g. "Flame Captain" or "恒辉队上尉").
+    pub fn rank_title_in(&self, rank: GrandCompanyRank, language: Language) -> String {
+        let word = self.title_word_in(language);
+        let rank = rank.name_in(language);
+
+        match language {
+            Language::English => match rank.split_once(' ') {
+                Some((first, last)) if ["Chief", "Second", "First"].contains(&first) => {
+                    format!("{} {} {}", first, word, last)
+                }
+                _ => format!("{} {}", word, rank),
+            },
+            Language::German => format!("{}-{}", word, rank),
+            Language::French => format!("{} {}", rank, word),
+            Language::Korean => format!("{} {}", word, rank),
+            Language::Japanese | Language::ChineseSimplified | Language::ChineseTraditional => {
+                format!("{}{}", word, rank)
+            }
+        }
+    }
+
+    /// Returns the rank whose title in this Grand Company matches `title` in one of the given
+    /// client languages (e.g. "Flame Captain" for the Immortal Flames).
+    ///
+    /// This is case-insensitive.
+    pub fn rank_from_title_in(
+        &self,
+        title: &str,
+        languages: &[Language],
+    ) -> Result<GrandCompanyRank, UnknownVariant> {
+        let lower = title.trim().to_lowercase();
+
+        languages
+            .iter()
+            .find_map(|&language| {
+                GrandCompanyRank::VARIANTS
+                    .iter()
+                    .cloned()
+                    .find(|&rank| self.rank_title_in(rank, language).to_lowercase() == lower)
+            })
+            .ok_or_else(|| UnknownVariant("GrandCompanyRank", title.into()))
+    }
+
+    /// Returns the word standing for this Grand Company in its rank titles in the given client
+    /// language.
+    fn title_word_in(&self, language: Language) -> &'static str {
+        let words = match *self {
+            GrandCompany::Maelstrom => ["Storm", "黒渦団", "Sturm", "du Maelstrom", "黑涡团", "黑渦團", "흑와단"],
+            GrandCompany::TwinAdder => [
+                "Serpent",
+                "双蛇党",
+                "Schlangen",
+                "des Deux Vipères",
+                "双蛇党",
+                "雙蛇黨",
+                "쌍사당",
+            ],
+            GrandCompany::ImmortalFlames => [
+                "Flame",
+                "不滅隊",
+                "Flammen",
+                "des Immortels",
+                "恒辉队",
+                "恆輝隊",
+                "불멸대",
+            ],
+        };
+
+        words[language as usize]
+    }
+
+    /// Parses a string `s` as the official name of a Grand Company in one of the given client
+    /// languages or one of its community abbreviations (see [`GrandCompany::abbreviations_in`]).
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(
+            s,
+            languages,
+            &GrandCompany::VARIANTS,
+            GrandCompany::name_in,
+            GrandCompany::abbreviations_in,
+        )
+        .ok_or_else(|| UnknownVariant("GrandCompany", s.into()))
+    }
+}
+
+impl FromStr for GrandCompany {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// The official name in every client language is accepted as well (see
+    /// [`GrandCompany::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grand_company = match s.to_lowercase().as_str() {
+            "maelstrom" => GrandCompany::Maelstrom,
+            "twinadder" | "twin adder" | "order of the twin adder" => GrandCompany::TwinAdder,
+            "immortalflames" | "immortal flames" => GrandCompany::ImmortalFlames,
+            _ => return GrandCompany::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(grand_company)
+    }
+}
+
+impl TryFrom<u16> for GrandCompany {
+    type Error = UnknownId;
+
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        GrandCompany::from_id(id).ok_or(UnknownId("GrandCompany", id))
+    }
+}
+
+impl Display for GrandCompany {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The ranks of the Grand Companies, from lowest to highest.
+///
+/// Each Grand Company has its own title for every rank (see [`GrandCompany::rank_title_in`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum GrandCompanyRank {
+    PrivateThirdClass,
+    PrivateSecondClass,
+    PrivateFirstClass,
+    Corporal,
+    SergeantThirdClass,
+    SergeantSecondClass,
+    SergeantFirstClass,
+    ChiefSergeant,
+    SecondLieutenant,
+    FirstLieutenant,
+    Captain,
+}
+
+impl GrandCompanyRank {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [GrandCompanyRank; 11] = GrandCompanyRank::VARIANTS;
+
+    const VARIANTS: [GrandCompanyRank; 11] = [
+        GrandCompanyRank::PrivateThirdClass,
+        GrandCompanyRank::PrivateSecondClass,
+        GrandCompanyRank::PrivateFirstClass,
+        GrandCompanyRank::Corporal,
+        GrandCompanyRank::SergeantThirdClass,
+        GrandCompanyRank::SergeantSecondClass,
+        GrandCompanyRank::SergeantFirstClass,
+        GrandCompanyRank::ChiefSergeant,
+        GrandCompanyRank::SecondLieutenant,
+        GrandCompanyRank::FirstLieutenant,
+        GrandCompanyRank::Captain,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            GrandCompanyRank::PrivateThirdClass => "PrivateThirdClass",
+            GrandCompanyRank::PrivateSecondClass => "PrivateSecondClass",
+            GrandCompanyRank::PrivateFirstClass => "PrivateFirstClass",
+            GrandCompanyRank::Corporal => "Corporal",
+            GrandCompanyRank::SergeantThirdClass => "SergeantThirdClass",
+            GrandCompanyRank::SergeantSecondClass => "SergeantSecondClass",
+            GrandCompanyRank::SergeantFirstClass => "SergeantFirstClass",
+            GrandCompanyRank::ChiefSergeant => "ChiefSergeant",
+            GrandCompanyRank::SecondLieutenant => "SecondLieutenant",
+            GrandCompanyRank::FirstLieutenant => "FirstLieutenant",
+            GrandCompanyRank::Captain => "Captain",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            GrandCompanyRank::PrivateThirdClass => "Private Third Class",
+            GrandCompanyRank::PrivateSecondClass => "Private Second Class",
+            GrandCompanyRank::PrivateFirstClass => "Private First Class",
+            GrandCompanyRank::Corporal => "Corporal",
+            GrandCompanyRank::SergeantThirdClass => "Sergeant Third Class",
+            GrandCompanyRank::SergeantSecondClass => "Sergeant Second Class",
+            GrandCompanyRank::SergeantFirstClass => "Sergeant First Class",
+            GrandCompanyRank::ChiefSergeant => "Chief Sergeant",
+            GrandCompanyRank::SecondLieutenant => "Second Lieutenant",
+            GrandCompanyRank::FirstLieutenant => "First Lieutenant",
+            GrandCompanyRank::Captain => "Captain",
+        }
+    }
+
+    /// Returns the name of this rank in the given client language, without the word standing for a
+    /// Grand Company.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            GrandCompanyRank::PrivateThirdClass => [
+                "Private Third Class",
+                "三等兵",
+                "Gefreiter 3. Klasse",
+                "Soldat de 3e classe",
+                "三等兵",
+                "三等兵",
+                "3등병",
+            ],
+            GrandCompanyRank::PrivateSecondClass => [
+                "Private Second Class",
+                "二等兵",
+                "Gefreiter 2. Klasse",
+                "Soldat de 2e classe",
+                "二等兵",
+                "二等兵",
+                "2등병",
+            ],
+            GrandCompanyRank::PrivateFirstClass => [
+                "Private First Class",
+                "一等兵",
+                "Gefreiter 1. Klasse",
+                "Soldat de 1re classe",
+                "一等兵",
+                "一等兵",
+                "1등병",
+            ],
+            GrandCompanyRank::Corporal => ["Corporal", "伍長", "Korporal", "Caporal", "下士", "下士", "하사"],
+            GrandCompanyRank::SergeantThirdClass => [
+                "Sergeant Third Class",
+                "三等軍曹",
+                "Unteroffizier 3. Klasse",
+                "Sergent de 3e classe",
+                "三等中士",
+                "三等中士",
+                "3등 중사",
+            ],
+            GrandCompanyRank::SergeantSecondClass => [
+                "Sergeant Second Class",
+                "二等軍曹",
+                "Unteroffizier 2. Klasse",
+                "Sergent de 2e classe",
+                "二等中士",
+                "二等中士",
+                "2등 중사",
+            ],
+            GrandCompanyRank::SergeantFirstClass => [
+                "Sergeant First Class",
+                "一等軍曹",
+                "Unteroffizier 1. Klasse",
+                "Sergent de 1re classe",
+                "一等中士",
+                "一等中士",
+                "1등 중사",
+            ],
+            GrandCompanyRank::ChiefSergeant => [
+                "Chief Sergeant",
+                "曹長",
+                "Oberfeldwebel",
+                "Sergent-chef",
+                "上士",
+                "上士",
+                "상사",
+            ],
+            GrandCompanyRank::SecondLieutenant => [
+                "Second Lieutenant",
+                "少尉",
+                "Leutnant",
+                "Sous-lieutenant",
+                "少尉",
+                "少尉",
+                "소위",
+            ],
+            GrandCompanyRank::FirstLieutenant => [
+                "First Lieutenant",
+                "中尉",
+                "Oberleutnant",
+                "Lieutenant",
+                "中尉",
+                "中尉",
+                "중위",
+            ],
+            GrandCompanyRank::Captain => ["Captain", "大尉", "Hauptmann", "Capitaine", "上尉", "上尉", "대위"],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns the number of this rank on the ladder, from 1 for Private Third Class to 11 for
+    /// Captain.
+    pub fn number(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    /// Returns the next rank on the ladder, or `None` for Captain.
+    pub fn next(&self) -> Option<GrandCompanyRank> {
+        GrandCompanyRank::VARIANTS.get(usize::from(self.number())).cloned()
+    }
+
+    /// Parses a string `s` as the name of a rank in one of the given client languages.
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        let variants = &GrandCompanyRank::VARIANTS;
+        languages::find_localized(s, languages, variants, GrandCompanyRank::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("GrandCompanyRank", s.into()))
+    }
+}
+
+impl FromStr for GrandCompanyRank {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts the name of the variant and the name with spaces between words. The name in
+    /// every client language is accepted as well (see [`GrandCompanyRank::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let variant = GrandCompanyRank::VARIANTS
+            .iter()
+            .cloned()
+            .find(|rank| rank.as_str().eq_ignore_ascii_case(s));
+
+        match variant {
+            Some(rank) => Ok(rank),
+            None => GrandCompanyRank::from_str_in(s, &Language::VARIANTS),
+        }
+    }
+}
+
+impl Display for GrandCompanyRank {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A character's membership of a [`GrandCompany`], with their rank.
+///
+/// This parses the Grand Company line of Lodestone character profiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub struct GrandCompanyMembership {
+    pub grand_company: GrandCompany,
+    pub rank: GrandCompanyRank,
+}
+
+impl GrandCompanyMembership {
+    /// Returns the title of the member in the given client language (e.g. "Flame Captain").
+    pub fn title_in(&self, language: Language) -> String {
+        self.grand_company.rank_title_in(self.rank, language)
+    }
+}
+
+impl FromStr for GrandCompanyMembership {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts the `Grand Company/title` form of Lodestone character profiles in every client
+    /// language (e.g. "Immortal Flames/Flame Captain" or "恒辉队/恒辉队上尉"). The title may also
+    /// be the name of the rank without the Grand Company (e.g. "Maelstrom/Captain").
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || UnknownVariant("GrandCompanyMembership", s.into());
+
+        let (grand_company, title) = s.split_once('/').ok_or_else(err)?;
+        let grand_company: GrandCompany = grand_company.trim().parse().map_err(|_| err())?;
+        let rank = grand_company
+            .rank_from_title_in(title, &Language::VARIANTS)
+            .or_else(|_| title.trim().parse())
+            .map_err(|_| err())?;
+
+        Ok(GrandCompanyMembership {
+            grand_company,
+            rank,
+        })
+    }
+}
+
+impl Display for GrandCompanyMembership {
+    /// Formats this membership as Lodestone does in English (e.g. "Immortal Flames/Flame Captain").
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}/{}", self.grand_company.name(), self.title_in(Language::English))
+    }
+}
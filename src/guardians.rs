@@ -1,6 +1,9 @@
 //! Guardian deity types
 
+#[cfg(feature = "calendar")]
+use calendar::Moon;
 use errors::UnknownVariant;
+use languages::{self, Language};
 
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
@@ -25,7 +28,9 @@ pub enum Guardian {
 
 impl Guardian {
     #[cfg(feature = "all_const")]
-    pub const ALL: [Guardian; 12] = [
+    pub const ALL: [Guardian; 12] = Guardian::VARIANTS;
+
+    const VARIANTS: [Guardian; 12] = [
         Guardian::Althyk,
         Guardian::Azeyma,
         Guardian::Byregot,
@@ -75,6 +80,50 @@ impl Guardian {
         }
     }
 
+    /// Returns the official name of this guardian in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            Guardian::Althyk => ["Althyk", "アルジク", "Althyk", "Althyk", "阿尔基克", "阿爾基克", "알디크"],
+            Guardian::Azeyma => ["Azeyma", "アーゼマ", "Azeyma", "Azeyma", "阿泽玛", "阿澤瑪", "아제마"],
+            Guardian::Byregot => ["Byregot", "ビエルゴ", "Byregot", "Byregot", "比尔格", "比爾格", "비레고"],
+            Guardian::Halone => ["Halone", "ハルオーネ", "Halone", "Halone", "哈罗妮", "哈羅妮", "할로네"],
+            Guardian::Llymlaen => [
+                "Llymlaen",
+                "リムレーン",
+                "Llymlaen",
+                "Llymlaen",
+                "利姆莱茵",
+                "利姆萊茵",
+                "리믈렌",
+            ],
+            Guardian::Menphina => [
+                "Menphina",
+                "メネフィナ",
+                "Menphina",
+                "Menphina",
+                "梅茵菲娜",
+                "梅茵菲娜",
+                "메느피나",
+            ],
+            Guardian::NaldThal => [
+                "Nald'thal",
+                "ナルザル",
+                "Nald'Thal",
+                "Nald'Thal",
+                "纳尔札尔",
+                "納爾札爾",
+                "날달",
+            ],
+            Guardian::Nophica => ["Nophica", "ノフィカ", "Nophica", "Nophica", "诺菲卡", "諾菲卡", "노피카"],
+            Guardian::Nymeia => ["Nymeia", "ニメーヤ", "Nymeia", "Nymeia", "妮美雅", "妮美雅", "니메이아"],
+            Guardian::Oschon => ["Oschon", "オシュオン", "Oschon", "Oschon", "奥修昂", "奧修昂", "오쉬온"],
+            Guardian::Rhalgr => ["Rhalgr", "ラールガー", "Rhalgr", "Rhalgr", "拉尔戈", "拉爾戈", "랄거"],
+            Guardian::Thaliak => ["Thaliak", "サリャク", "Thaliak", "Thaliak", "沙利亚克", "沙利亞克", "살리아크"],
+        };
+
+        names[language as usize]
+    }
+
     pub fn epithet(&self) -> &'static str {
         match *self {
             Guardian::Althyk => "the Keeper",
@@ -91,6 +140,33 @@ impl Guardian {
             Guardian::Thaliak => "the Scholar",
         }
     }
+
+    /// Returns the [`Moon`] this guardian rules, in which characters with this guardian are born.
+    #[cfg(feature = "calendar")]
+    pub fn moon(&self) -> Moon {
+        match *self {
+            Guardian::Halone => Moon::FirstAstral,
+            Guardian::Menphina => Moon::FirstUmbral,
+            Guardian::Thaliak => Moon::SecondAstral,
+            Guardian::Nymeia => Moon::SecondUmbral,
+            Guardian::Llymlaen => Moon::ThirdAstral,
+            Guardian::Oschon => Moon::ThirdUmbral,
+            Guardian::Byregot => Moon::FourthAstral,
+            Guardian::Rhalgr => Moon::FourthUmbral,
+            Guardian::Azeyma => Moon::FifthAstral,
+            Guardian::NaldThal => Moon::FifthUmbral,
+            Guardian::Nophica => Moon::SixthAstral,
+            Guardian::Althyk => Moon::SixthUmbral,
+        }
+    }
+
+    /// Parses a string `s` as the official name of a guardian in one of the given client languages.
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(s, languages, &Guardian::VARIANTS, Guardian::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("Guardian", s.into()))
+    }
 }
 
 impl FromStr for Guardian {
@@ -98,6 +174,8 @@ impl FromStr for Guardian {
 
     /// Parses a string `s` to return a value of this type.
     ///
+    /// The official name in every client language is accepted as well (see [`Guardian::from_str_in`]).
+    ///
     /// This is case-insensitive.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let guardian = match s.to_lowercase().as_str() {
@@ -113,7 +191,7 @@ impl FromStr for Guardian {
             "oschon" => Guardian::Oschon,
             "rhalgr" => Guardian::Rhalgr,
             "thaliak" => Guardian::Thaliak,
-            _ => return Err(UnknownVariant("Guardian", s.into()))
+            _ => return Guardian::from_str_in(s, &Language::VARIANTS)
         };
 
         Ok(guardian)
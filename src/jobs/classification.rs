@@ -1,6 +1,7 @@
 //! Job classification types
 
 use errors::UnknownVariant;
+use languages::{self, Language};
 
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
@@ -22,7 +23,9 @@ pub enum Classification {
 
 impl Classification {
     #[cfg(feature = "all_const")]
-    pub const ALL: [Classification; 4] = [
+    pub const ALL: [Classification; 4] = Classification::VARIANTS;
+
+    const VARIANTS: [Classification; 4] = [
         Classification::War,
         Classification::Magic,
         Classification::Land,
@@ -46,6 +49,58 @@ impl Classification {
             Classification::Hand => "Disciple of the Hand",
         }
     }
+
+    /// Returns the official name of this classification in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            Classification::War => [
+                "Disciple of War",
+                "ファイター",
+                "Disziplin des Krieges",
+                "Disciple de la guerre",
+                "战斗精英",
+                "戰鬥精英",
+                "전투 계열",
+            ],
+            Classification::Magic => [
+                "Disciple of Magic",
+                "ソーサラー",
+                "Disziplin der Magie",
+                "Disciple de la magie",
+                "魔法导师",
+                "魔法導師",
+                "마법 계열",
+            ],
+            Classification::Land => [
+                "Disciple of the Land",
+                "ギャザラー",
+                "Disziplin des Sammelns",
+                "Disciple de la terre",
+                "大地使者",
+                "大地使者",
+                "채집 계열",
+            ],
+            Classification::Hand => [
+                "Disciple of the Hand",
+                "クラフター",
+                "Disziplin der Handfertigkeit",
+                "Disciple de la main",
+                "能工巧匠",
+                "能工巧匠",
+                "제작 계열",
+            ],
+        };
+
+        names[language as usize]
+    }
+
+    /// Parses a string `s` as the official name of a classification in one of the given client languages.
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(s, languages, &Classification::VARIANTS, Classification::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("Classification", s.into()))
+    }
 }
 
 impl FromStr for Classification {
@@ -57,7 +112,7 @@ impl FromStr for Classification {
             "disciple of magic" | "magic" => Classification::Magic,
             "disciple of the land" | "land" => Classification::Land,
             "disciple of the hand" | "hand" => Classification::Hand,
-            _ => return Err(UnknownVariant("Classification", s.into()))
+            _ => return Classification::from_str_in(s, &Language::VARIANTS)
         };
 
         Ok(class)
@@ -1,11 +1,22 @@
 //! Combat job types
 
-use errors::UnknownVariant;
+#[cfg(feature = "attributes")]
+use attributes::{Attribute, DamageType};
+#[cfg(feature = "city_states")]
+use city_states::CityState;
+use errors::{UnknownId, UnknownVariant};
+#[cfg(feature = "expansions")]
+use expansions::Expansion;
+#[cfg(feature = "gear")]
+use gear::GearCategory;
 #[cfg(feature = "job_classifications")]
 use jobs::classification::Classification;
+use languages::{self, Language};
 #[cfg(feature = "roles")]
-use roles::Role;
+use roles::{Role, SubRole};
+use versions::GameVersion;
 
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
 
@@ -47,7 +58,9 @@ pub enum Job {
 
 impl Job {
     #[cfg(feature = "all_const")]
-    pub const ALL: [Job; 23] = [
+    pub const ALL: [Job; 23] = Job::VARIANTS;
+
+    const VARIANTS: [Job; 23] = [
         // DPS
         Job::Bard,
         Job::BlackMage,
@@ -143,6 +156,104 @@ impl Job {
         }
     }
 
+    /// Returns the official name of this job in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            Job::Bard => ["Bard", "吟遊詩人", "Barde", "Barde", "吟游诗人", "吟遊詩人", "음유시인"],
+            Job::BlackMage => [
+                "Black Mage",
+                "黒魔道士",
+                "Schwarzmagier",
+                "Mage noir",
+                "黑魔法师",
+                "黑魔道士",
+                "흑마도사",
+            ],
+            Job::Dancer => ["Dancer", "踊り子", "Tänzer", "Danseur", "舞者", "舞者", "무도가"],
+            Job::Dragoon => ["Dragoon", "竜騎士", "Dragoon", "Chevalier dragon", "龙骑士", "龍騎士", "용기사"],
+            Job::Machinist => ["Machinist", "機工士", "Maschinist", "Machiniste", "机工士", "機工士", "기공사"],
+            Job::Monk => ["Monk", "モンク", "Mönch", "Moine", "武僧", "武僧", "몽크"],
+            Job::Ninja => ["Ninja", "忍者", "Ninja", "Ninja", "忍者", "忍者", "닌자"],
+            Job::Pictomancer => [
+                "Pictomancer",
+                "ピクトマンサー",
+                "Piktomant",
+                "Pictomancien",
+                "绘灵法师",
+                "繪靈法師",
+                "픽토맨서",
+            ],
+            Job::Reaper => ["Reaper", "リーパー", "Schnitter", "Faucheur", "钐镰客", "奪魂者", "리퍼"],
+            Job::RedMage => ["Red Mage", "赤魔道士", "Rotmagier", "Mage rouge", "赤魔法师", "赤魔道士", "적마도사"],
+            Job::Samurai => ["Samurai", "侍", "Samurai", "Samouraï", "武士", "武士", "사무라이"],
+            Job::Summoner => ["Summoner", "召喚士", "Beschwörer", "Invocateur", "召唤师", "召喚士", "소환사"],
+            Job::Viper => ["Viper", "ヴァイパー", "Viper", "Rôdeur vipère", "蝰蛇剑士", "蝰蛇劍士", "바이퍼"],
+
+            Job::Astrologian => [
+                "Astrologian",
+                "占星術師",
+                "Astrologe",
+                "Astromancien",
+                "占星术士",
+                "占星術士",
+                "점성술사",
+            ],
+            Job::Sage => ["Sage", "賢者", "Weiser", "Sage", "贤者", "賢者", "현자"],
+            Job::Scholar => ["Scholar", "学者", "Gelehrter", "Érudit", "学者", "學者", "학자"],
+            Job::WhiteMage => [
+                "White Mage",
+                "白魔道士",
+                "Weißmagier",
+                "Mage blanc",
+                "白魔法师",
+                "白魔道士",
+                "백마도사",
+            ],
+
+            Job::DarkKnight => [
+                "Dark Knight",
+                "暗黒騎士",
+                "Dunkelritter",
+                "Chevalier noir",
+                "暗黑骑士",
+                "暗黑騎士",
+                "암흑기사",
+            ],
+            Job::Gunbreaker => [
+                "Gunbreaker",
+                "ガンブレイカー",
+                "Revolverklinge",
+                "Pistosabreur",
+                "绝枪战士",
+                "絕槍戰士",
+                "건브레이커",
+            ],
+            Job::Paladin => ["Paladin", "ナイト", "Paladin", "Paladin", "骑士", "騎士", "나이트"],
+            Job::Warrior => ["Warrior", "戦士", "Krieger", "Guerrier", "战士", "戰士", "전사"],
+
+            Job::BlueMage => [
+                "Blue Mage",
+                "青魔道士",
+                "Blaumagier",
+                "Mage bleu",
+                "青魔法师",
+                "青魔道士",
+                "청마도사",
+            ],
+            Job::Beastmaster => [
+                "Beastmaster",
+                "ビーストマスター",
+                "Bestienbändiger",
+                "Belluaire",
+                "驯兽师",
+                "馴獸師",
+                "비스트마스터",
+            ],
+        };
+
+        names[language as usize]
+    }
+
     /// Returns the short code of this job.
     ///
     /// Short codes are fully capitalized (e.g. "BRD", "BLM").
@@ -177,6 +288,73 @@ impl Job {
         }
     }
 
+    /// Returns the row ID of this job in the game's `ClassJob` sheet.
+    pub fn id(&self) -> u16 {
+        match *self {
+            Job::Bard => 23,
+            Job::BlackMage => 25,
+            Job::Dancer => 38,
+            Job::Dragoon => 22,
+            Job::Machinist => 31,
+            Job::Monk => 20,
+            Job::Ninja => 30,
+            Job::Pictomancer => 42,
+            Job::Reaper => 39,
+            Job::RedMage => 35,
+            Job::Samurai => 34,
+            Job::Summoner => 27,
+            Job::Viper => 41,
+
+            Job::Astrologian => 33,
+            Job::Sage => 40,
+            Job::Scholar => 28,
+            Job::WhiteMage => 24,
+
+            Job::DarkKnight => 32,
+            Job::Gunbreaker => 37,
+            Job::Paladin => 19,
+            Job::Warrior => 21,
+
+            Job::BlueMage => 36,
+            Job::Beastmaster => 43,
+        }
+    }
+
+    /// Returns the job with the given row ID in the game's `ClassJob` sheet, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        let job = match id {
+            23 => Job::Bard,
+            25 => Job::BlackMage,
+            38 => Job::Dancer,
+            22 => Job::Dragoon,
+            31 => Job::Machinist,
+            20 => Job::Monk,
+            30 => Job::Ninja,
+            42 => Job::Pictomancer,
+            39 => Job::Reaper,
+            35 => Job::RedMage,
+            34 => Job::Samurai,
+            27 => Job::Summoner,
+            41 => Job::Viper,
+
+            33 => Job::Astrologian,
+            40 => Job::Sage,
+            28 => Job::Scholar,
+            24 => Job::WhiteMage,
+
+            32 => Job::DarkKnight,
+            37 => Job::Gunbreaker,
+            19 => Job::Paladin,
+            21 => Job::Warrior,
+
+            36 => Job::BlueMage,
+            43 => Job::Beastmaster,
+            _ => return None,
+        };
+
+        Some(job)
+    }
+
     /// Returns the [`Role`] for this job.
     #[cfg(feature = "roles")]
     pub fn role(&self) -> Role {
@@ -203,6 +381,113 @@ impl Job {
         }
     }
 
+    /// Returns the [`SubRole`] for this job, a finer grouping than its [`Role`].
+    #[cfg(feature = "roles")]
+    pub fn sub_role(&self) -> SubRole {
+        match *self {
+            Job::Dragoon
+            | Job::Monk
+            | Job::Ninja
+            | Job::Reaper
+            | Job::Samurai
+            | Job::Viper
+            | Job::Beastmaster => SubRole::Melee,
+
+            Job::Bard | Job::Dancer | Job::Machinist => SubRole::PhysicalRanged,
+
+            Job::BlackMage
+            | Job::Pictomancer
+            | Job::RedMage
+            | Job::Summoner
+            | Job::BlueMage => SubRole::MagicalRanged,
+
+            Job::Astrologian | Job::WhiteMage => SubRole::PureHealer,
+            Job::Sage | Job::Scholar => SubRole::BarrierHealer,
+
+            Job::DarkKnight | Job::Gunbreaker | Job::Paladin | Job::Warrior => SubRole::Tank,
+        }
+    }
+
+    /// Returns the primary [`Attribute`] this job scales with.
+    ///
+    /// The attribute of Beastmaster is provisional until the job's data is confirmed in the game.
+    #[cfg(feature = "attributes")]
+    pub fn primary_attribute(&self) -> Attribute {
+        match *self {
+            Job::Dragoon
+            | Job::Monk
+            | Job::Reaper
+            | Job::Samurai
+            | Job::Beastmaster
+            | Job::DarkKnight
+            | Job::Gunbreaker
+            | Job::Paladin
+            | Job::Warrior => Attribute::Strength,
+
+            Job::Bard | Job::Dancer | Job::Machinist | Job::Ninja | Job::Viper => {
+                Attribute::Dexterity
+            }
+
+            Job::BlackMage
+            | Job::Pictomancer
+            | Job::RedMage
+            | Job::Summoner
+            | Job::BlueMage => Attribute::Intelligence,
+
+            Job::Astrologian | Job::Sage | Job::Scholar | Job::WhiteMage => Attribute::Mind,
+        }
+    }
+
+    /// Returns the [`DamageType`] of this job's attacks and spells.
+    #[cfg(feature = "attributes")]
+    pub fn damage_type(&self) -> DamageType {
+        match *self {
+            Job::BlackMage
+            | Job::Pictomancer
+            | Job::RedMage
+            | Job::Summoner
+            | Job::BlueMage
+            | Job::Astrologian
+            | Job::Sage
+            | Job::Scholar
+            | Job::WhiteMage => DamageType::Magical,
+
+            _ => DamageType::Physical,
+        }
+    }
+
+    /// Returns the [`GearCategory`] of the armor this job wears.
+    ///
+    /// Its accessories are of the category's [`accessory_category`].
+    ///
+    /// The category of Beastmaster is provisional until the job's gear is confirmed in the game.
+    ///
+    /// [`accessory_category`]: GearCategory::accessory_category
+    #[cfg(feature = "gear")]
+    pub fn gear_category(&self) -> GearCategory {
+        match *self {
+            Job::DarkKnight | Job::Gunbreaker | Job::Paladin | Job::Warrior => {
+                GearCategory::Fending
+            }
+
+            Job::Dragoon | Job::Reaper | Job::Beastmaster => GearCategory::Maiming,
+
+            Job::Monk | Job::Samurai => GearCategory::Striking,
+
+            Job::Ninja | Job::Viper => GearCategory::Scouting,
+
+            Job::Bard | Job::Dancer | Job::Machinist => GearCategory::Aiming,
+
+            Job::Astrologian | Job::Sage | Job::Scholar | Job::WhiteMage => GearCategory::Healing,
+
+            Job::BlackMage
+            | Job::Pictomancer
+            | Job::RedMage
+            | Job::Summoner
+            | Job::BlueMage => GearCategory::Casting,
+        }
+    }
+
     /// Returns the [`Classification`] for this job.
     #[cfg(feature = "job_classifications")]
     pub fn classification(&self) -> Classification {
@@ -233,6 +518,195 @@ impl Job {
             | Job::BlueMage => Classification::Magic,
         }
     }
+
+    /// Returns the [`Class`] this job is unlocked from, if any.
+    ///
+    /// Jobs introduced after A Realm Reborn (e.g. Dark Knight, Dancer, Viper) have no base class.
+    pub fn base_class(&self) -> Option<Class> {
+        let class = match *self {
+            Job::Bard => Class::Archer,
+            Job::BlackMage => Class::Thaumaturge,
+            Job::Dragoon => Class::Lancer,
+            Job::Monk => Class::Pugilist,
+            Job::Ninja => Class::Rogue,
+            Job::Summoner => Class::Arcanist,
+
+            Job::Scholar => Class::Arcanist,
+            Job::WhiteMage => Class::Conjurer,
+
+            Job::Paladin => Class::Gladiator,
+            Job::Warrior => Class::Marauder,
+
+            _ => return None,
+        };
+
+        Some(class)
+    }
+
+    /// The level the [base class] of a job must reach before its job stone (soul crystal) can be
+    /// equipped.
+    ///
+    /// Every job with a base class unlocks at the same level, so this is a constant rather than a
+    /// per-job lookup. The job continues from the base class's level, so a level 30 Thaumaturge
+    /// becomes a level 30 Black Mage.
+    ///
+    /// [base class]: Job::base_class
+    pub const JOB_STONE_LEVEL: u8 = 30;
+
+    /// Returns the patch this job was added to the game in.
+    pub fn introduced_in(&self) -> GameVersion {
+        match *self {
+            Job::Bard
+            | Job::BlackMage
+            | Job::Dragoon
+            | Job::Monk
+            | Job::Summoner
+            | Job::Scholar
+            | Job::WhiteMage
+            | Job::Paladin
+            | Job::Warrior => GameVersion::new(2, 0),
+            Job::Ninja => GameVersion::new(2, 40),
+            Job::Astrologian | Job::DarkKnight | Job::Machinist => GameVersion::new(3, 0),
+            Job::RedMage | Job::Samurai => GameVersion::new(4, 0),
+            Job::BlueMage => GameVersion::new(4, 50),
+            Job::Dancer | Job::Gunbreaker => GameVersion::new(5, 0),
+            Job::Reaper | Job::Sage => GameVersion::new(6, 0),
+            Job::Pictomancer | Job::Viper => GameVersion::new(7, 0),
+            Job::Beastmaster => GameVersion::new(7, 40),
+        }
+    }
+
+    /// Returns the level this job starts at when unlocked.
+    ///
+    /// Jobs with a base class start at the level their job stone is given (see
+    /// [`Job::JOB_STONE_LEVEL`]).
+    #[cfg(feature = "levels")]
+    pub fn starting_level(&self) -> u8 {
+        match *self {
+            Job::Bard
+            | Job::BlackMage
+            | Job::Dragoon
+            | Job::Monk
+            | Job::Ninja
+            | Job::Summoner
+            | Job::Scholar
+            | Job::WhiteMage
+            | Job::Paladin
+            | Job::Warrior
+            | Job::Astrologian
+            | Job::DarkKnight
+            | Job::Machinist => Job::JOB_STONE_LEVEL,
+            Job::RedMage | Job::Samurai => 50,
+            Job::Dancer | Job::Gunbreaker => 60,
+            Job::Reaper | Job::Sage => 70,
+            Job::Pictomancer | Job::Viper => 80,
+            Job::BlueMage | Job::Beastmaster => 1,
+        }
+    }
+
+    /// Returns the maximum level of this job in patch `version`, or `None` if the job was not in
+    /// the game yet.
+    ///
+    /// This is the level cap of the expansion, except for limited jobs, which have their own caps.
+    #[cfg(feature = "levels")]
+    pub fn max_level(&self, version: GameVersion) -> Option<u8> {
+        if version < self.introduced_in() {
+            return None;
+        }
+
+        let max_level = match *self {
+            Job::BlueMage => match version {
+                v if v >= GameVersion::new(6, 45) => 80,
+                v if v >= GameVersion::new(5, 45) => 70,
+                v if v >= GameVersion::new(5, 15) => 60,
+                _ => 50,
+            },
+            Job::Beastmaster => 20,
+            _ => Expansion::of(version).level_cap(),
+        };
+
+        Some(max_level)
+    }
+
+    /// Returns the [`Expansion`] an account needs to play this job.
+    ///
+    /// This is the expansion the job was added in, except for limited jobs, which only need A Realm
+    /// Reborn.
+    #[cfg(feature = "expansions")]
+    pub fn required_expansion(&self) -> Expansion {
+        match *self {
+            Job::BlueMage | Job::Beastmaster => Expansion::ARealmReborn,
+            _ => Expansion::of(self.introduced_in()),
+        }
+    }
+
+    /// Returns common community abbreviations of this job's name in the given client language
+    /// (e.g. "黑魔" for Black Mage in Simplified Chinese).
+    pub fn abbreviations_in(&self, language: Language) -> &'static [&'static str] {
+        match language {
+            Language::Japanese => match *self {
+                Job::BlackMage => &["黒魔"],
+                Job::Dragoon => &["竜"],
+                Job::Machinist => &["機工"],
+                Job::RedMage => &["赤魔"],
+                Job::Summoner => &["召喚"],
+                Job::Astrologian => &["占星"],
+                Job::WhiteMage => &["白魔"],
+                Job::DarkKnight => &["暗黒"],
+                Job::Gunbreaker => &["ガンブレ"],
+                Job::BlueMage => &["青魔"],
+                _ => &[],
+            },
+            Language::ChineseSimplified => match *self {
+                Job::Bard => &["诗人"],
+                Job::BlackMage => &["黑魔"],
+                Job::Dancer => &["舞娘"],
+                Job::Dragoon => &["龙骑"],
+                Job::Machinist => &["机工"],
+                Job::Monk => &["和尚"],
+                Job::Pictomancer => &["绘灵", "画家"],
+                Job::Reaper => &["钐镰", "镰刀"],
+                Job::RedMage => &["赤魔", "红魔"],
+                Job::Summoner => &["召唤"],
+                Job::Viper => &["蝰蛇"],
+                Job::Astrologian => &["占星"],
+                Job::WhiteMage => &["白魔"],
+                Job::DarkKnight => &["暗骑", "黑骑"],
+                Job::Gunbreaker => &["绝枪", "枪刃"],
+                Job::Paladin => &["圣骑"],
+                Job::BlueMage => &["青魔", "蓝魔"],
+                _ => &[],
+            },
+            Language::ChineseTraditional => match *self {
+                Job::Bard => &["詩人"],
+                Job::BlackMage => &["黑魔"],
+                Job::Dragoon => &["龍騎"],
+                Job::Machinist => &["機工"],
+                Job::Pictomancer => &["繪靈"],
+                Job::RedMage => &["赤魔"],
+                Job::Summoner => &["召喚"],
+                Job::Astrologian => &["占星"],
+                Job::WhiteMage => &["白魔"],
+                Job::DarkKnight => &["暗騎"],
+                Job::Gunbreaker => &["絕槍"],
+                Job::BlueMage => &["青魔"],
+                _ => &[],
+            },
+            _ => &[],
+        }
+    }
+
+    /// Parses a string `s` as the official name of a job in one of the given client languages or
+    /// one of its community abbreviations (see [`Job::abbreviations_in`]).
+    ///
+    /// This is case-insensitive. See [`languages`] for the forms only [`FromStr`] accepts.
+    ///
+    /// [`languages`]: ::languages
+    /// [`FromStr`]: ::std::str::FromStr
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(s, languages, &Job::VARIANTS, Job::name_in, Job::abbreviations_in)
+            .ok_or_else(|| UnknownVariant("Job", s.into()))
+    }
 }
 
 impl FromStr for Job {
@@ -242,6 +716,8 @@ impl FromStr for Job {
     ///
     /// This accepts the name of the variant as a string, the name of the variant as a string with
     /// spaces between words, and the shortened job code for each variant (e.g. "BLM" for Black Mage).
+    /// The official name and community abbreviations in every client language are accepted as well
+    /// (see [`Job::from_str_in`]).
     ///
     /// This is case-insensitive.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -273,13 +749,21 @@ impl FromStr for Job {
             "blue mage" | "bluemage" | "blu" => Job::BlueMage,
             "beast master" | "beastmaster" | "bst" => Job::Beastmaster,
 
-            _ => return Err(UnknownVariant("Job", s.into())),
+            _ => return Job::from_str_in(s, &Language::VARIANTS),
         };
 
         Ok(job)
     }
 }
 
+impl TryFrom<u16> for Job {
+    type Error = UnknownId;
+
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        Job::from_id(id).ok_or(UnknownId("Job", id))
+    }
+}
+
 impl Display for Job {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         write!(f, "{}", self.name())
@@ -304,7 +788,9 @@ pub enum Class {
 
 impl Class {
     #[cfg(feature = "all_const")]
-    pub const ALL: [Class; 9] = [
+    pub const ALL: [Class; 9] = Self::VARIANTS;
+
+    const VARIANTS: [Class; 9] = [
         Self::Arcanist,
         Self::Archer,
         Self::Lancer,
@@ -337,6 +823,33 @@ impl Class {
         self.as_str()
     }
 
+    /// Returns the official name of this class in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match self {
+            Self::Arcanist => ["Arcanist", "巴術士", "Hermetiker", "Arcaniste", "秘术师", "秘術師", "비술사"],
+            Self::Archer => ["Archer", "弓術士", "Waldläufer", "Archer", "弓箭手", "弓箭手", "궁술사"],
+            Self::Lancer => ["Lancer", "槍術士", "Pikenier", "Maître d'hast", "枪术师", "槍術師", "창술사"],
+            Self::Pugilist => ["Pugilist", "格闘士", "Faustkämpfer", "Pugiliste", "格斗家", "格鬥家", "격투사"],
+            Self::Rogue => ["Rogue", "双剣士", "Schurke", "Surineur", "双剑师", "雙劍師", "쌍검사"],
+            Self::Thaumaturge => [
+                "Thaumaturge",
+                "呪術士",
+                "Thaumaturg",
+                "Occultiste",
+                "咒术师",
+                "咒術師",
+                "주술사",
+            ],
+
+            Self::Conjurer => ["Conjurer", "幻術士", "Druide", "Élémentaliste", "幻术师", "幻術師", "환술사"],
+
+            Self::Gladiator => ["Gladiator", "剣術士", "Gladiator", "Gladiateur", "剑术师", "劍術師", "검술사"],
+            Self::Marauder => ["Marauder", "斧術士", "Marodeur", "Maraudeur", "斧术师", "斧術師", "도끼술사"],
+        };
+
+        names[language as usize]
+    }
+
     // code
     pub fn code(&self) -> &'static str {
         match self {
@@ -354,6 +867,43 @@ impl Class {
         }
     }
 
+    /// Returns the row ID of this class in the game's `ClassJob` sheet.
+    pub fn id(&self) -> u16 {
+        match self {
+            Self::Arcanist => 26,
+            Self::Archer => 5,
+            Self::Lancer => 4,
+            Self::Pugilist => 2,
+            Self::Rogue => 29,
+            Self::Thaumaturge => 7,
+
+            Self::Conjurer => 6,
+
+            Self::Gladiator => 1,
+            Self::Marauder => 3,
+        }
+    }
+
+    /// Returns the class with the given row ID in the game's `ClassJob` sheet, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        let class = match id {
+            26 => Self::Arcanist,
+            5 => Self::Archer,
+            4 => Self::Lancer,
+            2 => Self::Pugilist,
+            29 => Self::Rogue,
+            7 => Self::Thaumaturge,
+
+            6 => Self::Conjurer,
+
+            1 => Self::Gladiator,
+            3 => Self::Marauder,
+            _ => return None,
+        };
+
+        Some(class)
+    }
+
     // role
     #[cfg(feature = "roles")]
     pub fn role(&self) -> Role {
@@ -371,6 +921,22 @@ impl Class {
         }
     }
 
+    // sub-role
+    #[cfg(feature = "roles")]
+    pub fn sub_role(&self) -> SubRole {
+        match self {
+            Self::Lancer | Self::Pugilist | Self::Rogue => SubRole::Melee,
+
+            Self::Archer => SubRole::PhysicalRanged,
+
+            Self::Arcanist | Self::Thaumaturge => SubRole::MagicalRanged,
+
+            Self::Conjurer => SubRole::PureHealer,
+
+            Self::Gladiator | Self::Marauder => SubRole::Tank,
+        }
+    }
+
     // classification
     #[cfg(feature = "job_classifications")]
     pub fn classification(&self) -> Classification {
@@ -385,4 +951,104 @@ impl Class {
             Self::Arcanist | Self::Thaumaturge | Self::Conjurer => Classification::Magic,
         }
     }
+
+    /// Returns the [`Job`]s this class can become.
+    ///
+    /// Every class leads to a single job except Arcanist, which leads to both Summoner and Scholar.
+    pub fn jobs(&self) -> &'static [Job] {
+        match self {
+            Self::Arcanist => &[Job::Summoner, Job::Scholar],
+            Self::Archer => &[Job::Bard],
+            Self::Lancer => &[Job::Dragoon],
+            Self::Pugilist => &[Job::Monk],
+            Self::Rogue => &[Job::Ninja],
+            Self::Thaumaturge => &[Job::BlackMage],
+
+            Self::Conjurer => &[Job::WhiteMage],
+
+            Self::Gladiator => &[Job::Paladin],
+            Self::Marauder => &[Job::Warrior],
+        }
+    }
+
+    /// Returns the [`CityState`] the guild of this class is in.
+    #[cfg(feature = "city_states")]
+    pub fn guild_city(&self) -> CityState {
+        match self {
+            Self::Arcanist | Self::Marauder | Self::Rogue => CityState::LimsaLominsa,
+            Self::Archer | Self::Conjurer | Self::Lancer => CityState::Gridania,
+            Self::Gladiator | Self::Pugilist | Self::Thaumaturge => CityState::UlDah,
+        }
+    }
+
+    /// Returns the patch this class was added to the game in.
+    pub fn introduced_in(&self) -> GameVersion {
+        match self {
+            Self::Rogue => GameVersion::new(2, 40),
+            _ => GameVersion::new(2, 0),
+        }
+    }
+
+    /// Returns whether this class can be chosen at character creation.
+    ///
+    /// Every class can except Rogue, which is unlocked in game.
+    pub fn is_starting_class(&self) -> bool {
+        *self != Self::Rogue
+    }
+
+    /// Parses a string `s` as the official name of a class in one of the given client languages.
+    ///
+    /// This is case-insensitive. See [`languages`] for the forms only [`FromStr`] accepts.
+    ///
+    /// [`languages`]: ::languages
+    /// [`FromStr`]: ::std::str::FromStr
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(s, languages, &Self::VARIANTS, Self::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("Class", s.into()))
+    }
+}
+
+impl FromStr for Class {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts the name of the variant and the shortened class code for each variant (e.g.
+    /// "THM" for Thaumaturge). The official name in every client language is accepted as well (see
+    /// [`Class::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let class = match s.to_lowercase().as_str() {
+            "arcanist" | "acn" => Self::Arcanist,
+            "archer" | "arc" => Self::Archer,
+            "lancer" | "lnc" => Self::Lancer,
+            "pugilist" | "pgl" => Self::Pugilist,
+            "rogue" | "rog" => Self::Rogue,
+            "thaumaturge" | "thm" => Self::Thaumaturge,
+
+            "conjurer" | "cnj" => Self::Conjurer,
+
+            "gladiator" | "gla" => Self::Gladiator,
+            "marauder" | "mrd" => Self::Marauder,
+
+            _ => return Self::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(class)
+    }
+}
+
+impl TryFrom<u16> for Class {
+    type Error = UnknownId;
+
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        Class::from_id(id).ok_or(UnknownId("Class", id))
+    }
+}
+
+impl Display for Class {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
 }
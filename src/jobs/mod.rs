@@ -14,9 +14,27 @@ pub use self::combat::{Class, Job};
 #[cfg(feature = "non_combat_jobs")]
 pub use self::non_combat::NonCombatJob;
 
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+use errors::{UnknownId, UnknownVariant};
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+use languages::Language;
+
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+use std::convert::TryFrom;
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+use std::fmt::{Display, Formatter, Result as FmtResult};
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+use std::str::FromStr;
+
+/// Any entry of the game's `ClassJob` sheet: a [`Class`], a [`Job`] or a [`NonCombatJob`].
+///
+/// With feature `with_serde`, this serializes as its `ClassJob` row ID. Human-readable formats such
+/// as JSON also deserialize it from anything accepted by [`FromStr`]; other formats only accept the
+/// row ID.
+///
+/// [`FromStr`]: ::std::str::FromStr
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
-#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
 pub enum ClassJob {
     #[cfg(feature = "combat_jobs")]
     Class(Class),
@@ -28,6 +46,54 @@ pub enum ClassJob {
 
 #[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
 impl ClassJob {
+    /// Every entry of the `ClassJob` sheet, in sheet order.
+    #[cfg(all(feature = "all_const", feature = "combat_jobs", feature = "non_combat_jobs"))]
+    pub const ALL: [ClassJob; 43] = [
+        ClassJob::Class(Class::Gladiator),
+        ClassJob::Class(Class::Pugilist),
+        ClassJob::Class(Class::Marauder),
+        ClassJob::Class(Class::Lancer),
+        ClassJob::Class(Class::Archer),
+        ClassJob::Class(Class::Conjurer),
+        ClassJob::Class(Class::Thaumaturge),
+        ClassJob::NonCombat(NonCombatJob::Carpenter),
+        ClassJob::NonCombat(NonCombatJob::Blacksmith),
+        ClassJob::NonCombat(NonCombatJob::Armorer),
+        ClassJob::NonCombat(NonCombatJob::Goldsmith),
+        ClassJob::NonCombat(NonCombatJob::Leatherworker),
+        ClassJob::NonCombat(NonCombatJob::Weaver),
+        ClassJob::NonCombat(NonCombatJob::Alchemist),
+        ClassJob::NonCombat(NonCombatJob::Culinarian),
+        ClassJob::NonCombat(NonCombatJob::Miner),
+        ClassJob::NonCombat(NonCombatJob::Botanist),
+        ClassJob::NonCombat(NonCombatJob::Fisher),
+        ClassJob::Job(Job::Paladin),
+        ClassJob::Job(Job::Monk),
+        ClassJob::Job(Job::Warrior),
+        ClassJob::Job(Job::Dragoon),
+        ClassJob::Job(Job::Bard),
+        ClassJob::Job(Job::WhiteMage),
+        ClassJob::Job(Job::BlackMage),
+        ClassJob::Class(Class::Arcanist),
+        ClassJob::Job(Job::Summoner),
+        ClassJob::Job(Job::Scholar),
+        ClassJob::Class(Class::Rogue),
+        ClassJob::Job(Job::Ninja),
+        ClassJob::Job(Job::Machinist),
+        ClassJob::Job(Job::DarkKnight),
+        ClassJob::Job(Job::Astrologian),
+        ClassJob::Job(Job::Samurai),
+        ClassJob::Job(Job::RedMage),
+        ClassJob::Job(Job::BlueMage),
+        ClassJob::Job(Job::Gunbreaker),
+        ClassJob::Job(Job::Dancer),
+        ClassJob::Job(Job::Reaper),
+        ClassJob::Job(Job::Sage),
+        ClassJob::Job(Job::Viper),
+        ClassJob::Job(Job::Pictomancer),
+        ClassJob::Job(Job::Beastmaster),
+    ];
+
     #[cfg(feature = "combat_jobs")]
     pub fn as_job(&self) -> Option<Job> {
         match self {
@@ -66,6 +132,17 @@ impl ClassJob {
         }
     }
 
+    pub fn name_in(&self, language: Language) -> &'static str {
+        match self {
+            #[cfg(feature = "combat_jobs")]
+            Self::Job(j) => j.name_in(language),
+            #[cfg(feature = "combat_jobs")]
+            Self::Class(c) => c.name_in(language),
+            #[cfg(feature = "non_combat_jobs")]
+            Self::NonCombat(nc) => nc.name_in(language),
+        }
+    }
+
     pub fn code(&self) -> &'static str {
         match self {
             #[cfg(feature = "combat_jobs")]
@@ -77,12 +154,46 @@ impl ClassJob {
         }
     }
 
+    /// Returns the row ID of this entry in the game's `ClassJob` sheet.
+    pub fn id(&self) -> u16 {
+        match self {
+            #[cfg(feature = "combat_jobs")]
+            Self::Job(j) => j.id(),
+            #[cfg(feature = "combat_jobs")]
+            Self::Class(c) => c.id(),
+            #[cfg(feature = "non_combat_jobs")]
+            Self::NonCombat(nc) => nc.id(),
+        }
+    }
+
+    /// Returns the entry with the given row ID in the game's `ClassJob` sheet, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        #[cfg(feature = "combat_jobs")]
+        {
+            if let Some(j) = Job::from_id(id) {
+                return Some(Self::Job(j));
+            }
+            if let Some(c) = Class::from_id(id) {
+                return Some(Self::Class(c));
+            }
+        }
+        #[cfg(feature = "non_combat_jobs")]
+        {
+            if let Some(nc) = NonCombatJob::from_id(id) {
+                return Some(Self::NonCombat(nc));
+            }
+        }
+
+        None
+    }
+
     #[cfg(all(feature = "roles", feature = "combat_jobs"))]
     pub fn role(&self) -> Option<crate::Role> {
         match self {
             Self::Job(j) => Some(j.role()),
             Self::Class(c) => Some(c.role()),
-            _ => None,
+            #[cfg(feature = "non_combat_jobs")]
+            Self::NonCombat(_) => None,
         }
     }
 
@@ -98,3 +209,127 @@ impl ClassJob {
         }
     }
 }
+
+#[cfg(feature = "combat_jobs")]
+impl From<Job> for ClassJob {
+    fn from(job: Job) -> Self {
+        ClassJob::Job(job)
+    }
+}
+
+#[cfg(feature = "combat_jobs")]
+impl From<Class> for ClassJob {
+    fn from(class: Class) -> Self {
+        ClassJob::Class(class)
+    }
+}
+
+#[cfg(feature = "non_combat_jobs")]
+impl From<NonCombatJob> for ClassJob {
+    fn from(job: NonCombatJob) -> Self {
+        ClassJob::NonCombat(job)
+    }
+}
+
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+impl FromStr for ClassJob {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts anything accepted by the [`FromStr`] implementations of [`Job`], [`Class`] and
+    /// [`NonCombatJob`], tried in that order.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[cfg(feature = "combat_jobs")]
+        {
+            if let Ok(j) = s.parse() {
+                return Ok(ClassJob::Job(j));
+            }
+            if let Ok(c) = s.parse() {
+                return Ok(ClassJob::Class(c));
+            }
+        }
+        #[cfg(feature = "non_combat_jobs")]
+        {
+            if let Ok(nc) = s.parse() {
+                return Ok(ClassJob::NonCombat(nc));
+            }
+        }
+
+        Err(UnknownVariant("ClassJob", s.into()))
+    }
+}
+
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+impl TryFrom<u16> for ClassJob {
+    type Error = UnknownId;
+
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        ClassJob::from_id(id).ok_or(UnknownId("ClassJob", id))
+    }
+}
+
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+impl Display for ClassJob {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(all(feature = "with_serde", any(feature = "combat_jobs", feature = "non_combat_jobs")))]
+mod serde_impls {
+    use super::ClassJob;
+
+    use serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+    use serde::ser::{Serialize, Serializer};
+
+    use std::convert::TryFrom;
+    use std::fmt::{Formatter, Result as FmtResult};
+
+    impl Serialize for ClassJob {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u16(self.id())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ClassJob {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(ClassJobVisitor)
+            } else {
+                deserializer.deserialize_u16(ClassJobVisitor)
+            }
+        }
+    }
+
+    struct ClassJobVisitor;
+
+    impl<'de> Visitor<'de> for ClassJobVisitor {
+        type Value = ClassJob;
+
+        fn expecting(&self, f: &mut Formatter) -> FmtResult {
+            write!(f, "a ClassJob row ID, name or code")
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            u16::try_from(v)
+                .ok()
+                .and_then(ClassJob::from_id)
+                .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            if v < 0 {
+                return Err(E::invalid_value(Unexpected::Signed(v), &self));
+            }
+
+            self.visit_u64(v as u64)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse().map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+        }
+    }
+}
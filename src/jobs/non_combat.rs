@@ -2,8 +2,13 @@
 
 #[cfg(feature = "job_classifications")]
 use jobs::classification::Classification;
-use errors::UnknownVariant;
+use errors::{UnknownId, UnknownVariant};
+#[cfg(feature = "expansions")]
+use expansions::Expansion;
+use languages::{self, Language};
+use versions::GameVersion;
 
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
 
@@ -29,7 +34,9 @@ pub enum NonCombatJob {
 
 impl NonCombatJob {
     #[cfg(feature = "all_const")]
-    pub const ALL: [NonCombatJob; 11] = [
+    pub const ALL: [NonCombatJob; 11] = NonCombatJob::VARIANTS;
+
+    const VARIANTS: [NonCombatJob; 11] = [
         NonCombatJob::Botanist,
         NonCombatJob::Fisher,
         NonCombatJob::Miner,
@@ -65,6 +72,90 @@ impl NonCombatJob {
         self.as_str()
     }
 
+    /// Returns the official name of this job in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            NonCombatJob::Botanist => [
+                "Botanist",
+                "園芸師",
+                "Gärtner",
+                "Botaniste",
+                "园艺工",
+                "園藝工",
+                "원예가",
+            ],
+            NonCombatJob::Fisher => ["Fisher", "漁師", "Fischer", "Pêcheur", "捕鱼人", "捕魚人", "어부"],
+            NonCombatJob::Miner => ["Miner", "採掘師", "Minenarbeiter", "Mineur", "采矿工", "採礦工", "광부"],
+
+            NonCombatJob::Alchemist => [
+                "Alchemist",
+                "錬金術師",
+                "Alchemist",
+                "Alchimiste",
+                "炼金术士",
+                "鍊金術士",
+                "연금술사",
+            ],
+            NonCombatJob::Armorer => [
+                "Armorer",
+                "甲冑師",
+                "Plattner",
+                "Armurier",
+                "铸甲匠",
+                "鑄甲匠",
+                "갑주제작사",
+            ],
+            NonCombatJob::Blacksmith => [
+                "Blacksmith",
+                "鍛冶師",
+                "Grobschmied",
+                "Forgeron",
+                "锻铁匠",
+                "鍛鐵匠",
+                "대장장이",
+            ],
+            NonCombatJob::Carpenter => [
+                "Carpenter",
+                "木工師",
+                "Zimmerer",
+                "Menuisier",
+                "刻木匠",
+                "刻木匠",
+                "목수",
+            ],
+            NonCombatJob::Culinarian => [
+                "Culinarian",
+                "調理師",
+                "Gourmet",
+                "Cuisinier",
+                "烹调师",
+                "烹調師",
+                "요리사",
+            ],
+            NonCombatJob::Goldsmith => [
+                "Goldsmith",
+                "彫金師",
+                "Goldschmied",
+                "Orfèvre",
+                "雕金匠",
+                "雕金匠",
+                "보석공예가",
+            ],
+            NonCombatJob::Leatherworker => [
+                "Leatherworker",
+                "革細工師",
+                "Gerber",
+                "Tanneur",
+                "制革匠",
+                "製革匠",
+                "가죽공예가",
+            ],
+            NonCombatJob::Weaver => ["Weaver", "裁縫師", "Weber", "Couturier", "裁衣匠", "裁衣匠", "재봉사"],
+        };
+
+        names[language as usize]
+    }
+
     pub fn code(&self) -> &'static str {
         match *self {
             NonCombatJob::Botanist => "BTN",
@@ -82,6 +173,45 @@ impl NonCombatJob {
         }
     }
 
+    /// Returns the row ID of this job in the game's `ClassJob` sheet.
+    pub fn id(&self) -> u16 {
+        match *self {
+            NonCombatJob::Botanist => 17,
+            NonCombatJob::Fisher => 18,
+            NonCombatJob::Miner => 16,
+
+            NonCombatJob::Alchemist => 14,
+            NonCombatJob::Armorer => 10,
+            NonCombatJob::Blacksmith => 9,
+            NonCombatJob::Carpenter => 8,
+            NonCombatJob::Culinarian => 15,
+            NonCombatJob::Goldsmith => 11,
+            NonCombatJob::Leatherworker => 12,
+            NonCombatJob::Weaver => 13,
+        }
+    }
+
+    /// Returns the job with the given row ID in the game's `ClassJob` sheet, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        let job = match id {
+            17 => NonCombatJob::Botanist,
+            18 => NonCombatJob::Fisher,
+            16 => NonCombatJob::Miner,
+
+            14 => NonCombatJob::Alchemist,
+            10 => NonCombatJob::Armorer,
+            9 => NonCombatJob::Blacksmith,
+            8 => NonCombatJob::Carpenter,
+            15 => NonCombatJob::Culinarian,
+            11 => NonCombatJob::Goldsmith,
+            12 => NonCombatJob::Leatherworker,
+            13 => NonCombatJob::Weaver,
+            _ => return None,
+        };
+
+        Some(job)
+    }
+
     #[cfg(feature = "job_classifications")]
     pub fn classification(&self) -> Classification {
         match *self {
@@ -99,6 +229,67 @@ impl NonCombatJob {
             NonCombatJob::Weaver => Classification::Hand,
         }
     }
+
+    /// Returns the patch this job was added to the game in.
+    ///
+    /// Every Disciple of the Land and of the Hand was in the game at the launch of A Realm Reborn.
+    pub fn introduced_in(&self) -> GameVersion {
+        GameVersion::new(2, 0)
+    }
+
+    /// Returns the [`Expansion`] an account needs to play this job.
+    #[cfg(feature = "expansions")]
+    pub fn required_expansion(&self) -> Expansion {
+        Expansion::of(self.introduced_in())
+    }
+
+    /// Returns common community abbreviations of this job's name in the given client language
+    /// (e.g. "炼金" for Alchemist in Simplified Chinese).
+    pub fn abbreviations_in(&self, language: Language) -> &'static [&'static str] {
+        match language {
+            Language::ChineseSimplified => match *self {
+                NonCombatJob::Botanist => &["园艺"],
+                NonCombatJob::Fisher => &["捕鱼", "钓鱼"],
+                NonCombatJob::Miner => &["采矿"],
+
+                NonCombatJob::Alchemist => &["炼金"],
+                NonCombatJob::Armorer => &["铸甲"],
+                NonCombatJob::Blacksmith => &["锻铁"],
+                NonCombatJob::Carpenter => &["刻木"],
+                NonCombatJob::Culinarian => &["烹调"],
+                NonCombatJob::Goldsmith => &["雕金"],
+                NonCombatJob::Leatherworker => &["制革"],
+                NonCombatJob::Weaver => &["裁衣"],
+            },
+            Language::ChineseTraditional => match *self {
+                NonCombatJob::Botanist => &["園藝"],
+                NonCombatJob::Fisher => &["捕魚", "釣魚"],
+                NonCombatJob::Miner => &["採礦"],
+
+                NonCombatJob::Alchemist => &["鍊金"],
+                NonCombatJob::Armorer => &["鑄甲"],
+                NonCombatJob::Blacksmith => &["鍛鐵"],
+                NonCombatJob::Carpenter => &["刻木"],
+                NonCombatJob::Culinarian => &["烹調"],
+                NonCombatJob::Goldsmith => &["雕金"],
+                NonCombatJob::Leatherworker => &["製革"],
+                NonCombatJob::Weaver => &["裁衣"],
+            },
+            _ => &[],
+        }
+    }
+
+    /// Parses a string `s` as the official name of a job in one of the given client languages or
+    /// one of its community abbreviations (see [`NonCombatJob::abbreviations_in`]).
+    ///
+    /// This is case-insensitive. See [`languages`] for the forms only [`FromStr`] accepts.
+    ///
+    /// [`languages`]: ::languages
+    /// [`FromStr`]: ::std::str::FromStr
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(s, languages, &NonCombatJob::VARIANTS, NonCombatJob::name_in, NonCombatJob::abbreviations_in)
+            .ok_or_else(|| UnknownVariant("NonCombatJob", s.into()))
+    }
 }
 
 impl FromStr for NonCombatJob {
@@ -118,13 +309,21 @@ impl FromStr for NonCombatJob {
             "goldsmith" | "gsm" => NonCombatJob::Goldsmith,
             "leatherworker" | "ltw" => NonCombatJob::Leatherworker,
             "weaver" | "wvr" => NonCombatJob::Weaver,
-            _ => return Err(UnknownVariant("NonCombatJob", s.into()))
+            _ => return NonCombatJob::from_str_in(s, &Language::VARIANTS)
         };
 
         Ok(job)
     }
 }
 
+impl TryFrom<u16> for NonCombatJob {
+    type Error = UnknownId;
+
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        NonCombatJob::from_id(id).ok_or(UnknownId("NonCombatJob", id))
+    }
+}
+
 impl Display for NonCombatJob {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         write!(f, "{}", self.name())
@@ -0,0 +1,180 @@
+//! Client language types
+//!
+//! Types with names in every client language have a `from_str_in` method, which only accepts the
+//! names (and abbreviations, if any) in the given languages. Language-neutral forms, such as the
+//! name of the variant or short codes, are only accepted by their [`FromStr`] implementation,
+//! which also tries every client language in turn.
+//!
+//! [`FromStr`]: ::std::str::FromStr
+
+use errors::UnknownVariant;
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The languages the game client is available in.
+///
+/// Global clients ship English, Japanese, German and French. The Chinese, Taiwanese and Korean
+/// services each ship their own client language.
+///
+/// The order of the variants is the order of the name tables used by the `name_in` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum Language {
+    English,
+    Japanese,
+    German,
+    French,
+    ChineseSimplified,
+    ChineseTraditional,
+    Korean,
+}
+
+impl Language {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [Language; 7] = Language::VARIANTS;
+
+    #[cfg(any(
+        feature = "all_const",
+        feature = "attributes",
+        feature = "calendar",
+        feature = "city_states",
+        feature = "clans",
+        feature = "combat_jobs",
+        feature = "expansions",
+        feature = "gear",
+        feature = "genders",
+        feature = "grand_companies",
+        feature = "guardians",
+        feature = "job_classifications",
+        feature = "non_combat_jobs",
+        feature = "parties",
+        feature = "races",
+        feature = "regions",
+        feature = "roles",
+        feature = "world_status",
+    ))]
+    pub(crate) const VARIANTS: [Language; 7] = [
+        Language::English,
+        Language::Japanese,
+        Language::German,
+        Language::French,
+        Language::ChineseSimplified,
+        Language::ChineseTraditional,
+        Language::Korean,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Language::English => "English",
+            Language::Japanese => "Japanese",
+            Language::German => "German",
+            Language::French => "French",
+            Language::ChineseSimplified => "ChineseSimplified",
+            Language::ChineseTraditional => "ChineseTraditional",
+            Language::Korean => "Korean",
+        }
+    }
+
+    /// Returns the name of this language, written in the language itself (e.g. "日本語").
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Language::English => "English",
+            Language::Japanese => "日本語",
+            Language::German => "Deutsch",
+            Language::French => "Français",
+            Language::ChineseSimplified => "简体中文",
+            Language::ChineseTraditional => "繁體中文",
+            Language::Korean => "한국어",
+        }
+    }
+
+    /// Returns the language tag of this language (e.g. "ja", "zh-CN").
+    pub fn code(&self) -> &'static str {
+        match *self {
+            Language::English => "en",
+            Language::Japanese => "ja",
+            Language::German => "de",
+            Language::French => "fr",
+            Language::ChineseSimplified => "zh-CN",
+            Language::ChineseTraditional => "zh-TW",
+            Language::Korean => "ko",
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts the English name of the language, its native name and its language tag
+    /// (e.g. "Japanese", "日本語" and "ja").
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = match s.to_lowercase().as_str() {
+            "english" | "en" => Language::English,
+            "japanese" | "日本語" | "ja" | "jp" => Language::Japanese,
+            "german" | "deutsch" | "de" => Language::German,
+            "french" | "français" | "francais" | "fr" => Language::French,
+            "chinesesimplified" | "chinese simplified" | "简体中文" | "zh-cn" | "zh-hans" | "chs" => {
+                Language::ChineseSimplified
+            }
+            "chinesetraditional" | "chinese traditional" | "繁體中文" | "zh-tw" | "zh-hant" | "cht" => {
+                Language::ChineseTraditional
+            }
+            "korean" | "한국어" | "ko" | "kr" => Language::Korean,
+            _ => return Err(UnknownVariant("Language", s.into())),
+        };
+
+        Ok(language)
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Finds the variant whose name or abbreviation in one of `languages` matches `s`.
+///
+/// This is case-insensitive.
+#[cfg(any(
+    feature = "attributes",
+    feature = "calendar",
+    feature = "city_states",
+    feature = "clans",
+    feature = "combat_jobs",
+    feature = "expansions",
+    feature = "gear",
+    feature = "genders",
+    feature = "grand_companies",
+    feature = "guardians",
+    feature = "job_classifications",
+    feature = "non_combat_jobs",
+    feature = "parties",
+    feature = "races",
+    feature = "regions",
+    feature = "roles",
+    feature = "world_status",
+))]
+pub(crate) fn find_localized<T: Copy>(
+    s: &str,
+    languages: &[Language],
+    variants: &[T],
+    name_in: fn(&T, Language) -> &'static str,
+    abbreviations_in: fn(&T, Language) -> &'static [&'static str],
+) -> Option<T> {
+    let s = s.to_lowercase();
+
+    languages.iter().find_map(|&language| {
+        variants.iter().cloned().find(|variant| {
+            name_in(variant, language).to_lowercase() == s
+                || abbreviations_in(variant, language)
+                    .iter()
+                    .any(|abbreviation| abbreviation.to_lowercase() == s)
+        })
+    })
+}
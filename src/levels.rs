@@ -0,0 +1,218 @@
+//! Level types
+
+use errors::InvalidLevel;
+#[cfg(feature = "non_combat_jobs")]
+use expansions::Expansion;
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+use jobs::ClassJob;
+#[cfg(feature = "combat_jobs")]
+use jobs::Job;
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+use versions::GameVersion;
+
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// A character level, from 1 to [`Level::MAX`].
+///
+/// With feature `with_serde`, levels are serialized as numbers, and deserializing a level checks
+/// that it is in range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "with_serde", serde(try_from = "u8", into = "u8"))]
+pub struct Level(u8);
+
+impl Level {
+    /// The lowest level.
+    pub const MIN: Level = Level(1);
+    /// The highest level of any job in the current expansion.
+    pub const MAX: Level = Level(100);
+
+    /// Creates a level, or returns `None` if `level` is not between [`Level::MIN`] and
+    /// [`Level::MAX`].
+    pub fn new(level: u8) -> Option<Level> {
+        if (Level::MIN.0..=Level::MAX.0).contains(&level) {
+            Some(Level(level))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a level `job` can be at in patch `version`: between [`Job::starting_level`] and
+    /// [`Job::max_level`].
+    #[cfg(feature = "combat_jobs")]
+    pub fn for_job(job: Job, level: u8, version: GameVersion) -> Result<Level, InvalidLevel> {
+        match job.max_level(version) {
+            Some(max_level) if (job.starting_level()..=max_level).contains(&level) => {
+                Ok(Level(level))
+            }
+            _ => Err(InvalidLevel(level)),
+        }
+    }
+
+    /// Returns this level as a number.
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns the EXP needed to go from this level to the next, or `None` at [`Level::MAX`].
+    pub fn exp_to_next(&self) -> Option<u32> {
+        EXP_TO_NEXT.get(usize::from(self.0) - 1).cloned()
+    }
+
+    /// Returns the total EXP needed to go from the start of level `from` to level `to`, or `0` if
+    /// `to` is not above `from`.
+    pub fn exp_to_reach(from: Level, to: Level) -> u64 {
+        EXP_TO_NEXT
+            .iter()
+            .take(usize::from(to.0) - 1)
+            .skip(usize::from(from.0) - 1)
+            .map(|&exp| u64::from(exp))
+            .sum()
+    }
+
+    /// Returns how far `exp` EXP into this level is towards the next, in percent, or `None` at
+    /// [`Level::MAX`] or if `exp` is enough to level up.
+    pub fn percent_to_next(&self, exp: u32) -> Option<f64> {
+        match self.exp_to_next() {
+            Some(exp_to_next) if exp < exp_to_next => {
+                Some(f64::from(exp) * 100.0 / f64::from(exp_to_next))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The EXP a class or job needs to level up in a given patch.
+///
+/// Every class and job needs the same EXP to go from one level to the next (see
+/// [`Level::exp_to_next`]), but they stop at different levels: classes stop at level 50 and limited
+/// jobs such as Blue Mage have their own, lower level caps.
+///
+/// Limited jobs have no EXP curve of their own: the game reads the EXP to the next level from a
+/// single table indexed by level (the `ExpToNext` column of the `ParamGrow` sheet), shared by
+/// every class and job.
+///
+/// With feature `with_serde`, EXP tables are not serializable: store the class or job and the patch
+/// and call [`ExpTable::new`] again instead.
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpTable {
+    min_level: Level,
+    max_level: Level,
+}
+
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+impl ExpTable {
+    /// Returns the EXP table of a class or job in patch `version`, or `None` if it was not in the
+    /// game yet.
+    pub fn new<J: Into<ClassJob>>(class_job: J, version: GameVersion) -> Option<ExpTable> {
+        let (min_level, max_level) = match class_job.into() {
+            #[cfg(feature = "combat_jobs")]
+            ClassJob::Job(job) => (job.starting_level(), job.max_level(version)?),
+            #[cfg(feature = "combat_jobs")]
+            ClassJob::Class(class) if version >= class.introduced_in() => (1, CLASS_LEVEL_CAP),
+            #[cfg(feature = "non_combat_jobs")]
+            ClassJob::NonCombat(job) if version >= job.introduced_in() => {
+                (1, Expansion::of(version).level_cap())
+            }
+            _ => return None,
+        };
+
+        Some(ExpTable {
+            min_level: Level(min_level),
+            max_level: Level(max_level),
+        })
+    }
+
+    /// Returns the level the class or job starts at.
+    pub fn min_level(&self) -> Level {
+        self.min_level
+    }
+
+    /// Returns the level cap of the class or job.
+    pub fn max_level(&self) -> Level {
+        self.max_level
+    }
+
+    /// Returns the EXP needed to go from `level` to the next, or `None` if `level` is not below the
+    /// level cap.
+    pub fn exp_to_next(&self, level: Level) -> Option<u32> {
+        if level < self.min_level || level >= self.max_level {
+            return None;
+        }
+
+        level.exp_to_next()
+    }
+
+    /// Returns the total EXP needed to go from the start of level `from` to level `to`, or `None`
+    /// if either level is outside of the level range of the class or job.
+    pub fn exp_to_reach(&self, from: Level, to: Level) -> Option<u64> {
+        let range = self.min_level..=self.max_level;
+        if !range.contains(&from) || !range.contains(&to) {
+            return None;
+        }
+
+        Some(Level::exp_to_reach(from, to))
+    }
+
+    /// Returns how far `exp` EXP into `level` is towards the next, in percent, or `None` if
+    /// `level` is not below the level cap or if `exp` is enough to level up.
+    pub fn percent_to_next(&self, level: Level, exp: u32) -> Option<f64> {
+        self.exp_to_next(level)?;
+        level.percent_to_next(exp)
+    }
+}
+
+impl TryFrom<u8> for Level {
+    type Error = InvalidLevel;
+
+    fn try_from(level: u8) -> Result<Self, Self::Error> {
+        Level::new(level).ok_or(InvalidLevel(level))
+    }
+}
+
+impl From<Level> for u8 {
+    fn from(level: Level) -> u8 {
+        level.0
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The highest level of classes: they have to turn into their job to go further.
+#[cfg(feature = "combat_jobs")]
+const CLASS_LEVEL_CAP: u8 = 50;
+
+/// The EXP needed to go from each level to the next, from level 1 to 99.
+const EXP_TO_NEXT: [u32; 99] = [
+    // 1-10
+    300, 600, 1_100, 1_700, 2_300, 4_200, 6_000, 7_350, 9_930, 11_800,
+    // 11-20
+    15_600, 19_600, 23_700, 26_400, 30_500, 35_400, 40_500, 45_700, 51_000, 56_600,
+    // 21-30
+    63_900, 71_400, 79_100, 87_100, 95_200, 109_800, 124_800, 140_200, 155_900, 162_500,
+    // 31-40
+    175_900, 189_600, 203_500, 217_900, 232_320, 249_900, 267_800, 286_200, 304_900, 324_000,
+    // 41-50
+    340_200, 356_800, 373_700, 390_800, 408_200, 437_600, 467_500, 498_000, 529_000, 864_000,
+    // 51-60
+    1_058_400, 1_267_200, 1_555_200, 1_872_000, 2_217_600, 2_592_000, 2_995_200, 3_427_200,
+    3_888_000, 4_470_000,
+    // 61-70
+    4_873_000, 5_316_000, 5_809_000, 6_364_000, 6_995_000, 7_722_000, 8_575_000, 9_593_000,
+    10_826_000, 12_449_000,
+    // 71-80
+    13_881_000, 15_095_000, 16_145_000, 17_079_000, 17_925_000, 18_706_000, 19_440_000,
+    20_136_000, 20_797_000, 21_430_000,
+    // 81-90
+    22_038_000, 22_624_000, 23_191_000, 23_739_000, 24_269_000, 24_783_000, 25_282_000,
+    25_767_000, 26_239_000, 27_150_000,
+    // 91-99
+    27_590_000, 28_020_000, 28_440_000, 28_850_000, 29_250_000, 29_640_000, 30_020_000,
+    30_390_000, 30_750_000,
+];
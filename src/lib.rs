@@ -1,39 +1,106 @@
 //! Types for use in FFXIV-related projects.
 
+#[cfg(feature = "with_serde")]
+extern crate serde;
 #[cfg(feature = "with_serde")]
 #[macro_use]
 extern crate serde_derive;
 
+#[cfg(feature = "attributes")]
+pub mod attributes;
+#[cfg(feature = "calendar")]
+pub mod calendar;
+#[cfg(feature = "city_states")]
+pub mod city_states;
 #[cfg(feature = "clans")]
 pub mod clans;
 #[cfg(feature = "data_centers")]
 pub mod data_centers;
 pub mod errors;
+#[cfg(feature = "expansions")]
+pub mod expansions;
+#[cfg(feature = "formulas")]
+pub mod formulas;
+#[cfg(feature = "gear")]
+pub mod gear;
+#[cfg(feature = "genders")]
+pub mod genders;
+#[cfg(feature = "grand_companies")]
+pub mod grand_companies;
 #[cfg(feature = "guardians")]
 pub mod guardians;
 pub mod jobs;
+pub mod languages;
+#[cfg(feature = "levels")]
+pub mod levels;
+#[cfg(feature = "parties")]
+pub mod parties;
+#[cfg(feature = "profiles")]
+pub mod profiles;
 #[cfg(feature = "races")]
 pub mod races;
+#[cfg(feature = "regions")]
+pub mod regions;
 #[cfg(feature = "roles")]
 pub mod roles;
+pub mod versions;
+#[cfg(feature = "world_status")]
+pub mod world_status;
 #[cfg(feature = "worlds")]
 pub mod worlds;
 
+#[cfg(feature = "attributes")]
+pub use self::attributes::{Attribute, DamageType};
+#[cfg(feature = "calendar")]
+pub use self::calendar::{EorzeaTime, Moon, MoonHalf, Nameday};
+#[cfg(feature = "city_states")]
+pub use self::city_states::CityState;
 #[cfg(feature = "clans")]
 pub use self::clans::Clan;
 #[cfg(feature = "data_centers")]
 pub use self::data_centers::DataCenter;
+#[cfg(feature = "expansions")]
+pub use self::expansions::Expansion;
+#[cfg(feature = "formulas")]
+pub use self::formulas::{Formulas, LevelModifiers, Stats};
+#[cfg(feature = "gear")]
+pub use self::gear::GearCategory;
+#[cfg(feature = "genders")]
+pub use self::genders::Gender;
+#[cfg(feature = "grand_companies")]
+pub use self::grand_companies::{GrandCompany, GrandCompanyMembership, GrandCompanyRank};
 #[cfg(feature = "guardians")]
 pub use self::guardians::Guardian;
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+pub use self::jobs::ClassJob;
 #[cfg(feature = "combat_jobs")]
 pub use self::jobs::Job;
 #[cfg(feature = "non_combat_jobs")]
 pub use self::jobs::NonCombatJob;
 #[cfg(feature = "job_classifications")]
 pub use self::jobs::Classification;
+pub use self::languages::Language;
+#[cfg(feature = "levels")]
+pub use self::levels::Level;
+#[cfg(all(feature = "levels", any(feature = "combat_jobs", feature = "non_combat_jobs")))]
+pub use self::levels::ExpTable;
+#[cfg(feature = "parties")]
+pub use self::parties::{
+    Assignment, Composition, CompositionIssue, NoAssignment, Party, PartyBonus, PartyKind, Player,
+    RoleCategory,
+};
+#[cfg(feature = "profiles")]
+pub use self::profiles::{CharacterProfile, InvalidProfile};
 #[cfg(feature = "races")]
 pub use self::races::Race;
+#[cfg(feature = "regions")]
+pub use self::regions::{Publisher, Region};
 #[cfg(feature = "roles")]
-pub use self::roles::Role;
+pub use self::roles::{Role, SubRole};
+pub use self::versions::GameVersion;
 #[cfg(feature = "worlds")]
 pub use self::worlds::World;
+#[cfg(all(feature = "worlds", feature = "regions"))]
+pub use self::worlds::TravelKind;
+#[cfg(feature = "world_status")]
+pub use self::world_status::{WorldCategory, WorldStatus, WorldStatusTable};
@@ -0,0 +1,859 @@
+//! Party types
+
+#[cfg(feature = "with_serde")]
+use errors::PartyTooLarge;
+use errors::UnknownVariant;
+use jobs::{ClassJob, Job};
+use languages::{self, Language};
+use roles::{Role, SubRole};
+
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The sizes of parties in the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum PartyKind {
+    /// A party of 4, e.g. for dungeons.
+    Light,
+    /// A party of 8, e.g. for trials and raids.
+    Full,
+    /// Three full parties, e.g. for alliance raids.
+    Alliance,
+}
+
+impl PartyKind {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [PartyKind; 3] = PartyKind::VARIANTS;
+
+    const VARIANTS: [PartyKind; 3] = [PartyKind::Light, PartyKind::Full, PartyKind::Alliance];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            PartyKind::Light => "Light",
+            PartyKind::Full => "Full",
+            PartyKind::Alliance => "Alliance",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            PartyKind::Light => "Light Party",
+            PartyKind::Full => "Full Party",
+            PartyKind::Alliance => "Alliance",
+        }
+    }
+
+    /// Returns the name of this kind of party in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            PartyKind::Light => [
+                "Light Party",
+                "ライトパーティ",
+                "Leichte Gruppe",
+                "Petite équipe",
+                "轻装小队",
+                "輕裝小隊",
+                "경파티",
+            ],
+            PartyKind::Full => [
+                "Full Party",
+                "フルパーティ",
+                "Volle Gruppe",
+                "Équipe complète",
+                "满编小队",
+                "滿編小隊",
+                "풀파티",
+            ],
+            PartyKind::Alliance => [
+                "Alliance",
+                "アライアンス",
+                "Allianz",
+                "Alliance",
+                "团队",
+                "團隊",
+                "연합 파티",
+            ],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns the number of members of a party of this kind.
+    pub fn size(&self) -> usize {
+        match *self {
+            PartyKind::Light => 4,
+            PartyKind::Full => 8,
+            PartyKind::Alliance => 24,
+        }
+    }
+
+    /// Parses a string `s` as the name of a kind of party in one of the given client languages.
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        let variants = &PartyKind::VARIANTS;
+        languages::find_localized(s, languages, variants, PartyKind::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("PartyKind", s.into()))
+    }
+}
+
+impl FromStr for PartyKind {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts the name of the variant and the name of the kind of party. The name in every
+    /// client language is accepted as well (see [`PartyKind::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let kind = match s.to_lowercase().as_str() {
+            "light" | "light party" => PartyKind::Light,
+            "full" | "full party" => PartyKind::Full,
+            "alliance" => PartyKind::Alliance,
+            _ => return PartyKind::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(kind)
+    }
+}
+
+impl Display for PartyKind {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The roles a party has to be made of, e.g. 2 tanks, 2 healers and 4 DPS.
+///
+/// Sub-role counts are minimums within the DPS slots: a composition with `dps: 4` and `melee: 1`
+/// needs at least one melee DPS among its four DPS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub struct Composition {
+    pub tanks: u8,
+    pub healers: u8,
+    pub dps: u8,
+    pub melee: u8,
+    pub physical_ranged: u8,
+    pub magical_ranged: u8,
+    /// Whether the same job may be played by more than one member.
+    pub allow_duplicate_jobs: bool,
+}
+
+impl Composition {
+    /// 1 tank, 1 healer and 2 DPS, as in dungeons.
+    pub const LIGHT_PARTY: Composition = Composition::new(1, 1, 2);
+
+    /// 2 tanks, 2 healers and 4 DPS, as in trials and raids.
+    pub const FULL_PARTY: Composition = Composition::new(2, 2, 4);
+
+    /// 2 tanks, 2 healers and 4 DPS with at least a melee, a physical ranged and a magical ranged
+    /// DPS, and no duplicate job, as usually asked for by high-end raiding parties.
+    pub const STANDARD_FULL_PARTY: Composition = Composition {
+        melee: 1,
+        physical_ranged: 1,
+        magical_ranged: 1,
+        allow_duplicate_jobs: false,
+        ..Composition::FULL_PARTY
+    };
+
+    /// 3 tanks, 6 healers and 15 DPS, as in alliance raids.
+    ///
+    /// The roles are counted across the whole alliance, not per party of 8: an alliance with both
+    /// tanks of a party in another party still satisfies this composition.
+    pub const ALLIANCE_RAID: Composition = Composition::new(3, 6, 15);
+
+    /// Creates a composition with the given number of each role, no sub-role requirement and
+    /// duplicate jobs allowed.
+    pub const fn new(tanks: u8, healers: u8, dps: u8) -> Composition {
+        Composition {
+            tanks,
+            healers,
+            dps,
+            melee: 0,
+            physical_ranged: 0,
+            magical_ranged: 0,
+            allow_duplicate_jobs: true,
+        }
+    }
+
+    /// Returns the number of members of a party with this composition.
+    pub fn size(&self) -> usize {
+        usize::from(self.tanks) + usize::from(self.healers) + usize::from(self.dps)
+    }
+
+    /// Returns the number of members this composition needs with the given role.
+    pub fn role_count(&self, role: Role) -> u8 {
+        match role {
+            Role::Tank => self.tanks,
+            Role::Healer => self.healers,
+            Role::Dps => self.dps,
+        }
+    }
+
+    /// Returns the minimum number of members this composition needs with the given sub-role.
+    ///
+    /// Tank and healer sub-roles have no minimum of their own.
+    pub fn sub_role_count(&self, sub_role: SubRole) -> u8 {
+        match sub_role {
+            SubRole::Melee => self.melee,
+            SubRole::PhysicalRanged => self.physical_ranged,
+            SubRole::MagicalRanged => self.magical_ranged,
+            SubRole::Tank | SubRole::PureHealer | SubRole::BarrierHealer => 0,
+        }
+    }
+
+    /// Assigns `players` to the slots of this composition.
+    ///
+    /// The assignment is optimal: it has the lowest cost (see [`Assignment::cost`]), so everyone
+    /// plays their first choice whenever possible. Among equally good assignments, earlier players
+    /// and their preferred jobs are chosen first, so the result only depends on the order of
+    /// `players`.
+    ///
+    /// Players left over when there are more players than slots are not assigned. Leaving a player
+    /// out costs as much as giving them a job past the end of their preferences, so players with
+    /// fewer jobs are left out first.
+    ///
+    /// This is a branch-and-bound search, exponential in the number of players in the worst case.
+    /// It is meant for the party finder and alliance sizes of this crate, up to tens of players
+    /// with a handful of jobs each.
+    pub fn assign(&self, players: &[Player]) -> Result<Assignment, NoAssignment> {
+        if players.len() < self.size() {
+            return Err(NoAssignment::NotEnoughPlayers(self.size(), players.len()));
+        }
+        for &role in &[Role::Tank, Role::Healer, Role::Dps] {
+            let available = players.iter().filter(|player| player.plays_role(role)).count();
+            let needed = usize::from(self.role_count(role));
+            if available < needed {
+                return Err(NoAssignment::NotEnoughRole(role, needed, available));
+            }
+        }
+        for &sub_role in Role::Dps.sub_roles() {
+            let available = players.iter().filter(|player| player.plays_sub_role(sub_role)).count();
+            let needed = usize::from(self.sub_role_count(sub_role));
+            if available < needed {
+                return Err(NoAssignment::NotEnoughSubRole(sub_role, needed, available));
+            }
+        }
+
+        let mut search = AssignmentSearch::new(self, players);
+        search.run(0, 0);
+
+        search.best.ok_or(NoAssignment::Conflict)
+    }
+
+    /// Returns the recommended composition for a party of the given kind.
+    pub fn for_kind(kind: PartyKind) -> Composition {
+        match kind {
+            PartyKind::Light => Composition::LIGHT_PARTY,
+            PartyKind::Full => Composition::FULL_PARTY,
+            PartyKind::Alliance => Composition::ALLIANCE_RAID,
+        }
+    }
+}
+
+/// An error explaining why no players can be assigned to a [`Composition`].
+#[derive(Debug)]
+pub enum NoAssignment {
+    /// `(needed, available)`: there are fewer players than the composition has slots.
+    NotEnoughPlayers(usize, usize),
+    /// `(role, needed, available)`: fewer players can play the role than the composition needs.
+    NotEnoughRole(Role, usize, usize),
+    /// `(sub-role, needed, available)`: fewer players can play the sub-role than the composition
+    /// needs.
+    NotEnoughSubRole(SubRole, usize, usize),
+    /// Enough players can play each role, but not all at once (e.g. the only tank is also the only
+    /// healer), or the composition does not allow duplicate jobs and too many players only play
+    /// the same job.
+    Conflict,
+}
+
+impl Display for NoAssignment {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            NoAssignment::NotEnoughPlayers(needed, available) => {
+                write!(f, "{} players needed, but only {} available", needed, available)
+            }
+            NoAssignment::NotEnoughRole(role, needed, available) => {
+                write!(f, "{} {} needed, but only {} available", needed, role, available)
+            }
+            NoAssignment::NotEnoughSubRole(sub_role, needed, available) => {
+                write!(f, "{} {} needed, but only {} available", needed, sub_role, available)
+            }
+            NoAssignment::Conflict => {
+                write!(f, "players cannot fill every role of the composition at once")
+            }
+        }
+    }
+}
+
+/// A player signing up for a party, with the jobs they can play.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub struct Player {
+    pub name: String,
+    /// The jobs this player can play, from most to least preferred.
+    pub jobs: Vec<Job>,
+}
+
+impl Player {
+    pub fn new<S: Into<String>>(name: S, jobs: &[Job]) -> Player {
+        Player {
+            name: name.into(),
+            jobs: jobs.to_vec(),
+        }
+    }
+
+    fn plays_role(&self, role: Role) -> bool {
+        self.jobs.iter().any(|job| job.role() == role)
+    }
+
+    fn plays_sub_role(&self, sub_role: SubRole) -> bool {
+        self.jobs.iter().any(|job| job.sub_role() == sub_role)
+    }
+}
+
+/// Players assigned to the slots of a [`Composition`] (see [`Composition::assign`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub struct Assignment {
+    /// The index of each assigned player in the given players, with the job they play, in the
+    /// order of the players.
+    pub slots: Vec<(usize, Job)>,
+    /// The sum of the ranks of the assigned jobs in their players' preferences, from 0 for their
+    /// first choice, plus the number of jobs of each player left out.
+    pub cost: usize,
+}
+
+impl Assignment {
+    /// Returns the assigned players as a party of the given kind, or `None` if they do not fit.
+    pub fn party(&self, kind: PartyKind) -> Option<Party> {
+        let jobs: Vec<Job> = self.slots.iter().map(|&(_, job)| job).collect();
+
+        Party::with_jobs(kind, &jobs)
+    }
+}
+
+/// A branch-and-bound search for the best [`Assignment`], deciding for each player in turn which
+/// of their jobs they play, if any.
+struct AssignmentSearch<'a> {
+    composition: &'a Composition,
+    players: &'a [Player],
+    /// For each player, how many of the players from them on can play each role and DPS sub-role.
+    can_play: Vec<[usize; 6]>,
+    /// For each player, the last player before them with the same jobs, if any.
+    same_as: Vec<Option<usize>>,
+    choices: Vec<Option<Job>>,
+    counts: [usize; 6],
+    best: Option<Assignment>,
+}
+
+impl<'a> AssignmentSearch<'a> {
+    fn new(composition: &'a Composition, players: &'a [Player]) -> AssignmentSearch<'a> {
+        let mut can_play = vec![[0; 6]; players.len() + 1];
+        for (i, player) in players.iter().enumerate().rev() {
+            can_play[i] = can_play[i + 1];
+            for (slot, count) in can_play[i].iter_mut().enumerate() {
+                let plays = match slot {
+                    0..=2 => player.plays_role(ROLES[slot]),
+                    _ => player.plays_sub_role(DPS_SUB_ROLES[slot - 3]),
+                };
+                if plays {
+                    *count += 1;
+                }
+            }
+        }
+
+        let same_as = (0..players.len())
+            .map(|i| players[..i].iter().rposition(|other| other.jobs == players[i].jobs))
+            .collect();
+
+        AssignmentSearch {
+            composition,
+            players,
+            can_play,
+            same_as,
+            choices: vec![None; players.len()],
+            counts: [0; 6],
+            best: None,
+        }
+    }
+
+    /// Returns how many more players are needed with each role and DPS sub-role.
+    fn missing(&self) -> [usize; 6] {
+        let mut missing = [0; 6];
+        for (slot, missing) in missing.iter_mut().enumerate() {
+            let needed = usize::from(match slot {
+                0..=2 => self.composition.role_count(ROLES[slot]),
+                _ => self.composition.sub_role_count(DPS_SUB_ROLES[slot - 3]),
+            });
+            *missing = needed.saturating_sub(self.counts[slot]);
+        }
+
+        missing
+    }
+
+    /// Returns a lower bound of the cost of the players from `player` on: each of them plays a job
+    /// whose role is not full yet, at best their first one, or is left out, and every player beyond
+    /// the open slots has to be left out.
+    fn lowest_cost(&self, player: usize) -> usize {
+        let open = self.composition.size() - self.counts[..3].iter().sum::<usize>();
+        let left_out = (self.players.len() - player).saturating_sub(open);
+
+        let mut lowest = 0;
+        let mut extra_costs = Vec::with_capacity(self.players.len() - player);
+        for player in &self.players[player..] {
+            let rank = player.jobs.iter().position(|&job| !self.is_full(job.role()));
+            let rank = rank.unwrap_or(player.jobs.len());
+            lowest += rank;
+            extra_costs.push(player.jobs.len() - rank);
+        }
+        extra_costs.sort_unstable();
+
+        lowest + extra_costs[..left_out].iter().sum::<usize>()
+    }
+
+    fn is_full(&self, role: Role) -> bool {
+        self.counts[role_slot(role)] == usize::from(self.composition.role_count(role))
+    }
+
+    fn run(&mut self, player: usize, cost: usize) {
+        if let Some(ref best) = self.best {
+            if cost + self.lowest_cost(player) >= best.cost {
+                return;
+            }
+        }
+
+        let missing = self.missing();
+        if (0..6).any(|slot| missing[slot] > self.can_play[player][slot]) {
+            return;
+        }
+        let missing_sub_roles: usize = missing[3..].iter().sum();
+        if missing_sub_roles > usize::from(self.composition.dps) - self.counts[2] {
+            return;
+        }
+
+        if player == self.players.len() {
+            if missing.iter().all(|&missing| missing == 0) {
+                self.best = Some(Assignment {
+                    slots: (0..self.players.len())
+                        .filter_map(|i| self.choices[i].map(|job| (i, job)))
+                        .collect(),
+                    cost,
+                });
+            }
+            return;
+        }
+
+        // Players with the same jobs are interchangeable, so the earlier ones are assigned first.
+        let players = self.players;
+        let jobs = match self.same_as[player] {
+            Some(other) if self.choices[other].is_none() => &[],
+            _ => &players[player].jobs[..],
+        };
+        for (rank, &job) in jobs.iter().enumerate() {
+            if self.is_full(job.role()) {
+                continue;
+            }
+            if !self.composition.allow_duplicate_jobs && self.choices.contains(&Some(job)) {
+                continue;
+            }
+
+            let role = role_slot(job.role());
+            let sub_role = sub_role_slot(job.sub_role());
+            self.counts[role] += 1;
+            if let Some(sub_role) = sub_role {
+                self.counts[sub_role] += 1;
+            }
+            self.choices[player] = Some(job);
+
+            self.run(player + 1, cost + rank);
+
+            self.choices[player] = None;
+            if let Some(sub_role) = sub_role {
+                self.counts[sub_role] -= 1;
+            }
+            self.counts[role] -= 1;
+        }
+
+        self.run(player + 1, cost + players[player].jobs.len());
+    }
+}
+
+const ROLES: [Role; 3] = [Role::Tank, Role::Healer, Role::Dps];
+
+const DPS_SUB_ROLES: [SubRole; 3] = [
+    SubRole::Melee,
+    SubRole::PhysicalRanged,
+    SubRole::MagicalRanged,
+];
+
+fn role_slot(role: Role) -> usize {
+    match role {
+        Role::Tank => 0,
+        Role::Healer => 1,
+        Role::Dps => 2,
+    }
+}
+
+fn sub_role_slot(sub_role: SubRole) -> Option<usize> {
+    match sub_role {
+        SubRole::Melee => Some(3),
+        SubRole::PhysicalRanged => Some(4),
+        SubRole::MagicalRanged => Some(5),
+        SubRole::Tank | SubRole::PureHealer | SubRole::BarrierHealer => None,
+    }
+}
+
+/// A way a [`Party`] does not match a [`Composition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompositionIssue {
+    /// `(composition size, party size)`: the composition is for parties of another size than the
+    /// kind of the party.
+    SizeMismatch(usize, usize),
+    /// The party needs this many more members with the role.
+    MissingRole(Role, usize),
+    /// The party has this many members with the role too many.
+    ExtraRole(Role, usize),
+    /// The party needs this many more DPS with the sub-role.
+    MissingSubRole(SubRole, usize),
+    /// The job is played by this many members, but the composition does not allow duplicates.
+    DuplicateJob(Job, usize),
+}
+
+impl Display for CompositionIssue {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            CompositionIssue::SizeMismatch(composition, party) => {
+                write!(f, "composition of {} does not fit a party of {}", composition, party)
+            }
+            CompositionIssue::MissingRole(role, n) => write!(f, "missing {} {}", n, role),
+            CompositionIssue::ExtraRole(role, n) => write!(f, "{} {} too many", n, role),
+            CompositionIssue::MissingSubRole(sub_role, n) => {
+                write!(f, "missing {} {}", n, sub_role)
+            }
+            CompositionIssue::DuplicateJob(job, n) => write!(f, "{} is played {} times", job, n),
+        }
+    }
+}
+
+/// A party of players, each playing a [`Job`].
+///
+/// A party never has more members than its [`PartyKind`] has room for. With feature `with_serde`,
+/// deserializing a party checks this as well.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "with_serde", serde(try_from = "PartyFields"))]
+pub struct Party {
+    kind: PartyKind,
+    jobs: Vec<Job>,
+}
+
+impl Party {
+    /// Creates an empty party of the given kind.
+    pub fn new(kind: PartyKind) -> Party {
+        Party {
+            kind,
+            jobs: Vec::with_capacity(kind.size()),
+        }
+    }
+
+    /// Creates a party of the given kind with the given jobs, or returns `None` if there are more
+    /// jobs than the party has room for.
+    pub fn with_jobs(kind: PartyKind, jobs: &[Job]) -> Option<Party> {
+        if jobs.len() > kind.size() {
+            return None;
+        }
+
+        Some(Party {
+            kind,
+            jobs: jobs.to_vec(),
+        })
+    }
+
+    pub fn kind(&self) -> PartyKind {
+        self.kind
+    }
+
+    /// Returns the jobs of the members of this party, in the order they joined.
+    pub fn jobs(&self) -> &[Job] {
+        &self.jobs
+    }
+
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.jobs.len() >= self.kind.size()
+    }
+
+    /// Adds a member playing `job` to this party, returning `false` if the party is full.
+    pub fn push(&mut self, job: Job) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
+        self.jobs.push(job);
+        true
+    }
+
+    /// Returns the number of members of this party with the given role.
+    pub fn role_count(&self, role: Role) -> usize {
+        self.jobs.iter().filter(|job| job.role() == role).count()
+    }
+
+    /// Returns the number of members of this party with the given sub-role.
+    pub fn sub_role_count(&self, sub_role: SubRole) -> usize {
+        self.jobs.iter().filter(|job| job.sub_role() == sub_role).count()
+    }
+
+    /// Returns every way this party does not match `composition`, or an empty list if it does.
+    ///
+    /// A party that is not full yet reports the roles it is missing. A composition for another
+    /// size of party is reported as a [`CompositionIssue::SizeMismatch`] first.
+    pub fn check(&self, composition: &Composition) -> Vec<CompositionIssue> {
+        let mut issues = Vec::new();
+
+        if composition.size() != self.kind.size() {
+            issues.push(CompositionIssue::SizeMismatch(composition.size(), self.kind.size()));
+        }
+
+        for &role in &[Role::Tank, Role::Healer, Role::Dps] {
+            let (have, need) = (self.role_count(role), usize::from(composition.role_count(role)));
+            if have < need {
+                issues.push(CompositionIssue::MissingRole(role, need - have));
+            } else if have > need {
+                issues.push(CompositionIssue::ExtraRole(role, have - need));
+            }
+        }
+
+        for &sub_role in Role::Dps.sub_roles() {
+            let have = self.sub_role_count(sub_role);
+            let need = usize::from(composition.sub_role_count(sub_role));
+            if have < need {
+                issues.push(CompositionIssue::MissingSubRole(sub_role, need - have));
+            }
+        }
+
+        if !composition.allow_duplicate_jobs {
+            for (i, &job) in self.jobs.iter().enumerate() {
+                let count = self.jobs.iter().filter(|&&other| other == job).count();
+                if count > 1 && !self.jobs[..i].contains(&job) {
+                    issues.push(CompositionIssue::DuplicateJob(job, count));
+                }
+            }
+        }
+
+        issues
+    }
+
+    /// Returns whether this party matches `composition` (see [`Party::check`]).
+    pub fn satisfies(&self, composition: &Composition) -> bool {
+        self.check(composition).is_empty()
+    }
+
+    /// Returns the party bonus of this party (see [`PartyBonus`]).
+    pub fn bonus(&self) -> PartyBonus {
+        PartyBonus::of(self.jobs.iter().cloned())
+    }
+}
+
+/// The serialized form of a [`Party`], validated when deserializing.
+#[cfg(feature = "with_serde")]
+#[derive(Deserialize)]
+struct PartyFields {
+    kind: PartyKind,
+    jobs: Vec<Job>,
+}
+
+#[cfg(feature = "with_serde")]
+impl TryFrom<PartyFields> for Party {
+    type Error = PartyTooLarge;
+
+    fn try_from(fields: PartyFields) -> Result<Self, Self::Error> {
+        if fields.jobs.len() > fields.kind.size() {
+            return Err(PartyTooLarge(fields.jobs.len(), fields.kind.size()));
+        }
+
+        Ok(Party {
+            kind: fields.kind,
+            jobs: fields.jobs,
+        })
+    }
+}
+
+/// The role categories counted by the [`PartyBonus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum RoleCategory {
+    Tank,
+    Healer,
+    Melee,
+    PhysicalRanged,
+    MagicalRanged,
+}
+
+impl RoleCategory {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [RoleCategory; 5] = RoleCategory::VARIANTS;
+
+    const VARIANTS: [RoleCategory; 5] = [
+        RoleCategory::Tank,
+        RoleCategory::Healer,
+        RoleCategory::Melee,
+        RoleCategory::PhysicalRanged,
+        RoleCategory::MagicalRanged,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            RoleCategory::Tank => "Tank",
+            RoleCategory::Healer => "Healer",
+            RoleCategory::Melee => "Melee",
+            RoleCategory::PhysicalRanged => "PhysicalRanged",
+            RoleCategory::MagicalRanged => "MagicalRanged",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name_in(Language::English)
+    }
+
+    /// Returns the name of this category in the given client language, which is the name of the
+    /// matching [`Role`] or [`SubRole`].
+    pub fn name_in(&self, language: Language) -> &'static str {
+        match *self {
+            RoleCategory::Tank => Role::Tank.name_in(language),
+            RoleCategory::Healer => Role::Healer.name_in(language),
+            RoleCategory::Melee => SubRole::Melee.name_in(language),
+            RoleCategory::PhysicalRanged => SubRole::PhysicalRanged.name_in(language),
+            RoleCategory::MagicalRanged => SubRole::MagicalRanged.name_in(language),
+        }
+    }
+
+    /// Returns the category of the given sub-role.
+    pub fn from_sub_role(sub_role: SubRole) -> RoleCategory {
+        match sub_role {
+            SubRole::Tank => RoleCategory::Tank,
+            SubRole::PureHealer | SubRole::BarrierHealer => RoleCategory::Healer,
+            SubRole::Melee => RoleCategory::Melee,
+            SubRole::PhysicalRanged => RoleCategory::PhysicalRanged,
+            SubRole::MagicalRanged => RoleCategory::MagicalRanged,
+        }
+    }
+
+    /// Returns the category of the given class or job, or `None` for a [`NonCombatJob`].
+    ///
+    /// [`NonCombatJob`]: ::jobs::NonCombatJob
+    pub fn of<J: Into<ClassJob>>(class_job: J) -> Option<RoleCategory> {
+        let sub_role = match class_job.into() {
+            ClassJob::Job(job) => job.sub_role(),
+            ClassJob::Class(class) => class.sub_role(),
+            #[cfg(feature = "non_combat_jobs")]
+            ClassJob::NonCombat(_) => return None,
+        };
+
+        Some(RoleCategory::from_sub_role(sub_role))
+    }
+
+    /// Parses a string `s` as the name of a category in one of the given client languages.
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        let variants = &RoleCategory::VARIANTS;
+        languages::find_localized(s, languages, variants, RoleCategory::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("RoleCategory", s.into()))
+    }
+}
+
+impl FromStr for RoleCategory {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// The name in every client language is accepted as well (see [`RoleCategory::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let category = match s.to_lowercase().as_str() {
+            "tank" => RoleCategory::Tank,
+            "healer" => RoleCategory::Healer,
+            "melee" => RoleCategory::Melee,
+            "physicalranged" | "physical ranged" => RoleCategory::PhysicalRanged,
+            "magicalranged" | "magical ranged" => RoleCategory::MagicalRanged,
+            _ => return RoleCategory::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(category)
+    }
+}
+
+impl Display for RoleCategory {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The party bonus: the game raises the primary attributes of every party member by 1% for each
+/// distinct [`RoleCategory`] in the party, up to 5%.
+///
+/// With feature `with_serde`, party bonuses can be serialized but not deserialized: compute them
+/// with [`PartyBonus::of`] instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize))]
+pub struct PartyBonus {
+    covered: [bool; 5],
+}
+
+impl PartyBonus {
+    /// Returns the party bonus of a party with the given classes or jobs.
+    ///
+    /// Non-combat jobs do not count towards the bonus, and members sharing a category only count
+    /// once.
+    pub fn of<J, I>(members: I) -> PartyBonus
+    where
+        J: Into<ClassJob>,
+        I: IntoIterator<Item = J>,
+    {
+        let mut bonus = PartyBonus::default();
+        for category in members.into_iter().filter_map(RoleCategory::of) {
+            bonus.covered[category as usize] = true;
+        }
+
+        bonus
+    }
+
+    /// Returns whether a member of the party has the given category.
+    pub fn covers(&self, category: RoleCategory) -> bool {
+        self.covered[category as usize]
+    }
+
+    /// Returns the categories present in the party.
+    pub fn covered(&self) -> Vec<RoleCategory> {
+        RoleCategory::VARIANTS.iter().cloned().filter(|&c| self.covers(c)).collect()
+    }
+
+    /// Returns the categories missing from the party.
+    pub fn missing(&self) -> Vec<RoleCategory> {
+        RoleCategory::VARIANTS.iter().cloned().filter(|&c| !self.covers(c)).collect()
+    }
+
+    /// Returns the bonus in percent, from 0 to 5.
+    pub fn percent(&self) -> u8 {
+        self.covered.iter().map(|&covered| u8::from(covered)).sum()
+    }
+
+    /// Applies the bonus to a primary attribute value, rounding down like the game does.
+    pub fn apply(&self, attribute: u32) -> u32 {
+        let attribute = u64::from(attribute) * (100 + u64::from(self.percent())) / 100;
+        u32::try_from(attribute).unwrap_or(u32::MAX)
+    }
+}
@@ -0,0 +1,179 @@
+//! Character profile types
+
+use calendar::Nameday;
+use city_states::CityState;
+use clans::Clan;
+use genders::Gender;
+use guardians::Guardian;
+use jobs::Class;
+use races::Race;
+use versions::GameVersion;
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[cfg(feature = "with_serde")]
+use std::convert::TryFrom;
+
+/// A character as made at character creation.
+///
+/// Profiles are created with [`CharacterProfile::new`], which rejects combinations the game does
+/// not allow. With feature `with_serde`, deserializing a profile validates it the same way.
+///
+/// The guardian is chosen freely at character creation, so it does not have to match the one of
+/// the nameday (see [`Nameday::guardian`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "with_serde", serde(try_from = "ProfileFields"))]
+pub struct CharacterProfile {
+    race: Race,
+    clan: Clan,
+    gender: Gender,
+    nameday: Nameday,
+    guardian: Guardian,
+    city_state: CityState,
+    class: Class,
+    created_in: GameVersion,
+}
+
+impl CharacterProfile {
+    /// Creates the profile of a character made in patch `created_in`.
+    ///
+    /// The starting city-state is the one of the guild of the starting `class`.
+    ///
+    /// This fails if `clan` does not belong to `race`, if `gender` could not be chosen for `race`
+    /// in patch `created_in`, or if `class` cannot be chosen at character creation.
+    pub fn new(
+        race: Race,
+        clan: Clan,
+        gender: Gender,
+        nameday: Nameday,
+        guardian: Guardian,
+        class: Class,
+        created_in: GameVersion,
+    ) -> Result<CharacterProfile, InvalidProfile> {
+        if clan.race() != race {
+            return Err(InvalidProfile::ClanMismatch(race, clan));
+        }
+        if !race.available_genders(created_in).contains(&gender) {
+            return Err(InvalidProfile::GenderUnavailable(race, gender, created_in));
+        }
+        if !class.is_starting_class() {
+            return Err(InvalidProfile::NotStartingClass(class));
+        }
+
+        Ok(CharacterProfile {
+            race,
+            clan,
+            gender,
+            nameday,
+            guardian,
+            city_state: class.guild_city(),
+            class,
+            created_in,
+        })
+    }
+
+    pub fn race(&self) -> Race {
+        self.race
+    }
+
+    pub fn clan(&self) -> Clan {
+        self.clan
+    }
+
+    pub fn gender(&self) -> Gender {
+        self.gender
+    }
+
+    pub fn nameday(&self) -> Nameday {
+        self.nameday
+    }
+
+    pub fn guardian(&self) -> Guardian {
+        self.guardian
+    }
+
+    /// Returns the city-state the character started in.
+    pub fn city_state(&self) -> CityState {
+        self.city_state
+    }
+
+    /// Returns the class the character started as.
+    pub fn class(&self) -> Class {
+        self.class
+    }
+
+    /// Returns the patch the character was made in.
+    pub fn created_in(&self) -> GameVersion {
+        self.created_in
+    }
+}
+
+/// An error representing a [`CharacterProfile`] the game does not allow.
+#[derive(Debug)]
+pub enum InvalidProfile {
+    /// The clan does not belong to the race.
+    ClanMismatch(Race, Clan),
+    /// The gender could not be chosen for the race in the patch.
+    GenderUnavailable(Race, Gender, GameVersion),
+    /// The class cannot be chosen at character creation.
+    NotStartingClass(Class),
+    /// The city-state is not the one the class starts in.
+    CityStateMismatch(Class, CityState),
+}
+
+impl Display for InvalidProfile {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            InvalidProfile::ClanMismatch(race, clan) => {
+                write!(f, "clan {} does not belong to race {}", clan, race)
+            }
+            InvalidProfile::GenderUnavailable(race, gender, version) => {
+                write!(f, "{} {} could not be created in patch {}", gender, race, version)
+            }
+            InvalidProfile::NotStartingClass(class) => {
+                write!(f, "{} cannot be chosen at character creation", class)
+            }
+            InvalidProfile::CityStateMismatch(class, city_state) => {
+                write!(f, "{} does not start in {}", class, city_state)
+            }
+        }
+    }
+}
+
+/// The serialized form of a [`CharacterProfile`], validated when deserializing.
+#[cfg(feature = "with_serde")]
+#[derive(Deserialize)]
+struct ProfileFields {
+    race: Race,
+    clan: Clan,
+    gender: Gender,
+    nameday: Nameday,
+    guardian: Guardian,
+    city_state: CityState,
+    class: Class,
+    created_in: GameVersion,
+}
+
+#[cfg(feature = "with_serde")]
+impl TryFrom<ProfileFields> for CharacterProfile {
+    type Error = InvalidProfile;
+
+    fn try_from(fields: ProfileFields) -> Result<Self, Self::Error> {
+        let profile = CharacterProfile::new(
+            fields.race,
+            fields.clan,
+            fields.gender,
+            fields.nameday,
+            fields.guardian,
+            fields.class,
+            fields.created_in,
+        )?;
+
+        if profile.city_state != fields.city_state {
+            return Err(InvalidProfile::CityStateMismatch(fields.class, fields.city_state));
+        }
+
+        Ok(profile)
+    }
+}
@@ -1,9 +1,14 @@
 //! Race types
 
 use errors::UnknownVariant;
+use languages::{self, Language};
 
 #[cfg(feature = "clans")]
 use clans::Clan;
+#[cfg(feature = "genders")]
+use genders::Gender;
+#[cfg(feature = "genders")]
+use versions::GameVersion;
 
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
@@ -24,7 +29,9 @@ pub enum Race {
 
 impl Race {
     #[cfg(feature = "all_const")]
-    pub const ALL: [Race; 8] = [
+    pub const ALL: [Race; 8] = Race::VARIANTS;
+
+    const VARIANTS: [Race; 8] = [
         Race::AuRa,
         Race::Elezen,
         Race::Hrothgar,
@@ -62,6 +69,30 @@ impl Race {
         }
     }
 
+    /// Returns the official name of this race in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            Race::AuRa => ["Au Ra", "アウラ", "Au Ra", "Ao Ra", "敖龙族", "敖龍族", "아우라"],
+            Race::Elezen => ["Elezen", "エレゼン", "Elezen", "Elézen", "精灵族", "精靈族", "엘레젠"],
+            Race::Hrothgar => ["Hrothgar", "ロスガル", "Hrothgar", "Hrothgar", "硌狮族", "硌獅族", "로스갈"],
+            Race::Hyur => ["Hyur", "ヒューラン", "Hyuran", "Hyur", "人族", "人族", "휴런"],
+            Race::Lalafell => [
+                "Lalafell",
+                "ララフェル",
+                "Lalafell",
+                "Lalafell",
+                "拉拉菲尔族",
+                "拉拉菲爾族",
+                "라라펠",
+            ],
+            Race::Miqote => ["Miqo'te", "ミコッテ", "Miqo'te", "Miqo'te", "猫魅族", "貓魅族", "미코테"],
+            Race::Roegadyn => ["Roegadyn", "ルガディン", "Roegadyn", "Roegadyn", "鲁加族", "魯加族", "루가딘"],
+            Race::Viera => ["Viera", "ヴィエラ", "Viera", "Viera", "维埃拉族", "維埃拉族", "비에라"],
+        };
+
+        names[language as usize]
+    }
+
     #[cfg(feature = "clans")]
     pub fn clans(&self) -> [Clan; 2] {
         match *self {
@@ -75,6 +106,64 @@ impl Race {
             Race::Viera => [Clan::Rava, Clan::Veena],
         }
     }
+
+    /// Returns the genders that could be chosen for this race at character creation in patch
+    /// `version`.
+    ///
+    /// This is empty before the race was added to the game.
+    #[cfg(feature = "genders")]
+    pub fn available_genders(&self, version: GameVersion) -> &'static [Gender] {
+        let (male_since, female_since) = match *self {
+            Race::AuRa => (GameVersion::new(3, 0), GameVersion::new(3, 0)),
+            Race::Hrothgar => (GameVersion::new(5, 0), GameVersion::new(7, 0)),
+            Race::Viera => (GameVersion::new(6, 0), GameVersion::new(5, 0)),
+            _ => (GameVersion::new(2, 0), GameVersion::new(2, 0)),
+        };
+
+        match (version >= male_since, version >= female_since) {
+            (true, true) => &[Gender::Male, Gender::Female],
+            (true, false) => &[Gender::Male],
+            (false, true) => &[Gender::Female],
+            (false, false) => &[],
+        }
+    }
+
+    /// Returns common community abbreviations of this race's name in the given client language
+    /// (e.g. "猫魅" for Miqo'te in Simplified Chinese).
+    pub fn abbreviations_in(&self, language: Language) -> &'static [&'static str] {
+        match language {
+            Language::ChineseSimplified => match *self {
+                Race::AuRa => &["敖龙"],
+                Race::Elezen => &["精灵"],
+                Race::Hrothgar => &["硌狮"],
+                Race::Hyur => &["人类"],
+                Race::Lalafell => &["拉拉菲尔", "拉拉肥"],
+                Race::Miqote => &["猫魅"],
+                Race::Roegadyn => &["鲁加"],
+                Race::Viera => &["维埃拉"],
+            },
+            Language::ChineseTraditional => match *self {
+                Race::AuRa => &["敖龍"],
+                Race::Elezen => &["精靈"],
+                Race::Hrothgar => &["硌獅"],
+                Race::Hyur => &["人類"],
+                Race::Lalafell => &["拉拉菲爾"],
+                Race::Miqote => &["貓魅"],
+                Race::Roegadyn => &["魯加"],
+                Race::Viera => &["維埃拉"],
+            },
+            _ => &[],
+        }
+    }
+
+    /// Parses a string `s` as the official name of a race in one of the given client languages or one of its community
+    /// abbreviations (see [`Race::abbreviations_in`]).
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(s, languages, &Race::VARIANTS, Race::name_in, Race::abbreviations_in)
+            .ok_or_else(|| UnknownVariant("Race", s.into()))
+    }
 }
 
 impl FromStr for Race {
@@ -82,6 +171,8 @@ impl FromStr for Race {
 
     /// Parses a string `s` to return a value of this type.
     ///
+    /// The official name in every client language is accepted as well (see [`Race::from_str_in`]).
+    ///
     /// This is case-insensitive.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let world = match s.to_lowercase().as_str() {
@@ -93,7 +184,7 @@ impl FromStr for Race {
             "miqote" | "miqo'te" => Race::Miqote,
             "roegadyn" => Race::Roegadyn,
             "viera" => Race::Viera,
-            _ => return Err(UnknownVariant("Race", s.into()))
+            _ => return Race::from_str_in(s, &Language::VARIANTS)
         };
 
         Ok(world)
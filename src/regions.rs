@@ -0,0 +1,300 @@
+//! Region types
+
+use data_centers::DataCenter;
+use errors::UnknownVariant;
+use languages::{self, Language};
+#[cfg(feature = "worlds")]
+use worlds::World;
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The regions the game is served in.
+///
+/// Each [`DataCenter`] belongs to exactly one region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum Region {
+    NorthAmerica,
+    Europe,
+    Japan,
+    Oceania,
+    China,
+    Taiwan,
+    Korea,
+}
+
+impl Region {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [Region; 7] = Region::VARIANTS;
+
+    const VARIANTS: [Region; 7] = [
+        Region::NorthAmerica,
+        Region::Europe,
+        Region::Japan,
+        Region::Oceania,
+        Region::China,
+        Region::Taiwan,
+        Region::Korea,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Region::NorthAmerica => "NorthAmerica",
+            Region::Europe => "Europe",
+            Region::Japan => "Japan",
+            Region::Oceania => "Oceania",
+            Region::China => "China",
+            Region::Taiwan => "Taiwan",
+            Region::Korea => "Korea",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Region::NorthAmerica => "North America",
+            Region::Europe => "Europe",
+            Region::Japan => "Japan",
+            Region::Oceania => "Oceania",
+            Region::China => "China",
+            Region::Taiwan => "Taiwan",
+            Region::Korea => "Korea",
+        }
+    }
+
+    /// Returns the name of this region in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            Region::NorthAmerica => [
+                "North America",
+                "北米",
+                "Nordamerika",
+                "Amérique du Nord",
+                "北美",
+                "北美",
+                "북미",
+            ],
+            Region::Europe => ["Europe", "欧州", "Europa", "Europe", "欧洲", "歐洲", "유럽"],
+            Region::Japan => ["Japan", "日本", "Japan", "Japon", "日本", "日本", "일본"],
+            Region::Oceania => ["Oceania", "オセアニア", "Ozeanien", "Océanie", "大洋洲", "大洋洲", "오세아니아"],
+            Region::China => ["China", "中国", "China", "Chine", "中国", "中國", "중국"],
+            Region::Taiwan => ["Taiwan", "台湾", "Taiwan", "Taïwan", "台湾", "臺灣", "대만"],
+            Region::Korea => ["Korea", "韓国", "Korea", "Corée", "韩国", "韓國", "한국"],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns the short code of this region (e.g. "NA", "CN").
+    pub fn code(&self) -> &'static str {
+        match *self {
+            Region::NorthAmerica => "NA",
+            Region::Europe => "EU",
+            Region::Japan => "JP",
+            Region::Oceania => "OCE",
+            Region::China => "CN",
+            Region::Taiwan => "TW",
+            Region::Korea => "KR",
+        }
+    }
+
+    /// Returns the [`DataCenter`]s in this region.
+    pub fn data_centers(&self) -> &'static [DataCenter] {
+        match *self {
+            Region::NorthAmerica => &[
+                DataCenter::Aether,
+                DataCenter::Crystal,
+                DataCenter::Dynamis,
+                DataCenter::Primal,
+            ],
+            Region::Europe => &[DataCenter::Chaos, DataCenter::Light, DataCenter::Shadow],
+            Region::Japan => &[
+                DataCenter::Elemental,
+                DataCenter::Gaia,
+                DataCenter::Mana,
+                DataCenter::Meteor,
+            ],
+            Region::Oceania => &[DataCenter::Materia],
+            Region::China => &[
+                DataCenter::陆行鸟,
+                DataCenter::莫古力,
+                DataCenter::猫小胖,
+                DataCenter::豆豆柴,
+            ],
+            Region::Taiwan => &[DataCenter::陸行鳥],
+            Region::Korea => &[DataCenter::한국],
+        }
+    }
+
+    /// Returns the [`World`]s in this region, data center by data center.
+    #[cfg(feature = "worlds")]
+    pub fn worlds(&self) -> impl Iterator<Item = World> {
+        self.data_centers()
+            .iter()
+            .flat_map(|data_center| data_center.worlds().iter().cloned())
+    }
+
+    /// Returns the [`Publisher`] operating the game in this region.
+    pub fn publisher(&self) -> Publisher {
+        match *self {
+            Region::NorthAmerica | Region::Europe | Region::Japan | Region::Oceania => {
+                Publisher::SquareEnix
+            }
+            Region::China => Publisher::Shanda,
+            Region::Taiwan => Publisher::ChuanQi,
+            Region::Korea => Publisher::Actoz,
+        }
+    }
+
+    /// Parses a string `s` as the name of a region in one of the given client languages.
+    ///
+    /// This is case-insensitive. See [`languages`] for the forms only [`FromStr`] accepts.
+    ///
+    /// [`languages`]: ::languages
+    /// [`FromStr`]: ::std::str::FromStr
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(s, languages, &Region::VARIANTS, Region::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("Region", s.into()))
+    }
+}
+
+impl FromStr for Region {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts the name of the variant, the name with spaces between words and the short code
+    /// of each region (e.g. "OCE" for Oceania). The name in every client language is accepted as
+    /// well (see [`Region::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let region = match s.to_lowercase().as_str() {
+            "north america" | "northamerica" | "na" => Region::NorthAmerica,
+            "europe" | "eu" => Region::Europe,
+            "japan" | "jp" => Region::Japan,
+            "oceania" | "oce" => Region::Oceania,
+            "china" | "cn" | "国服" => Region::China,
+            "taiwan" | "tw" => Region::Taiwan,
+            "korea" | "kr" => Region::Korea,
+            _ => return Region::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(region)
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The companies operating the game.
+///
+/// Each [`Region`] is operated by exactly one publisher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum Publisher {
+    /// Square Enix, operating North America, Europe, Japan and Oceania.
+    SquareEnix,
+    /// Shanda Games (盛趣游戏), operating China.
+    Shanda,
+    /// 傳奇網路, operating Taiwan.
+    ChuanQi,
+    /// Actoz Soft, operating Korea.
+    Actoz,
+}
+
+impl Publisher {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [Publisher; 4] = [
+        Publisher::SquareEnix,
+        Publisher::Shanda,
+        Publisher::ChuanQi,
+        Publisher::Actoz,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Publisher::SquareEnix => "SquareEnix",
+            Publisher::Shanda => "Shanda",
+            Publisher::ChuanQi => "ChuanQi",
+            Publisher::Actoz => "Actoz",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Publisher::SquareEnix => "Square Enix",
+            Publisher::Shanda => "Shanda Games",
+            Publisher::ChuanQi => "傳奇網路",
+            Publisher::Actoz => "Actoz Soft",
+        }
+    }
+
+    /// Returns the name of this publisher in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            Publisher::SquareEnix => [
+                "Square Enix",
+                "スクウェア・エニックス",
+                "Square Enix",
+                "Square Enix",
+                "史克威尔艾尼克斯",
+                "史克威爾艾尼克斯",
+                "스퀘어 에닉스",
+            ],
+            Publisher::Shanda => [
+                "Shanda Games",
+                "盛趣游戏",
+                "Shanda Games",
+                "Shanda Games",
+                "盛趣游戏",
+                "盛趣遊戲",
+                "샨다 게임즈",
+            ],
+            Publisher::ChuanQi => [
+                "傳奇網路",
+                "傳奇網路",
+                "傳奇網路",
+                "傳奇網路",
+                "传奇网络",
+                "傳奇網路",
+                "傳奇網路",
+            ],
+            Publisher::Actoz => [
+                "Actoz Soft",
+                "アクトズソフト",
+                "Actoz Soft",
+                "Actoz Soft",
+                "Actoz Soft",
+                "Actoz Soft",
+                "액토즈소프트",
+            ],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns the [`Region`]s operated by this publisher.
+    pub fn regions(&self) -> &'static [Region] {
+        match *self {
+            Publisher::SquareEnix => &[
+                Region::NorthAmerica,
+                Region::Europe,
+                Region::Japan,
+                Region::Oceania,
+            ],
+            Publisher::Shanda => &[Region::China],
+            Publisher::ChuanQi => &[Region::Taiwan],
+            Publisher::Actoz => &[Region::Korea],
+        }
+    }
+}
+
+impl Display for Publisher {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
@@ -1,6 +1,7 @@
 //! Job role types
 
 use errors::UnknownVariant;
+use languages::{self, Language};
 
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
@@ -37,6 +38,26 @@ impl Role {
             Role::Tank => "Tank",
         }
     }
+
+    /// Returns the name of this role in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            Role::Dps => ["DPS", "DPS", "Angreifer", "DPS", "输出", "輸出", "공격"],
+            Role::Healer => ["Healer", "ヒーラー", "Heiler", "Soigneur", "治疗", "治療", "힐러"],
+            Role::Tank => ["Tank", "タンク", "Verteidiger", "Tank", "防护", "防護", "탱커"],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns the [`SubRole`]s this role is split into.
+    pub fn sub_roles(&self) -> &'static [SubRole] {
+        match *self {
+            Role::Dps => &[SubRole::Melee, SubRole::PhysicalRanged, SubRole::MagicalRanged],
+            Role::Healer => &[SubRole::PureHealer, SubRole::BarrierHealer],
+            Role::Tank => &[SubRole::Tank],
+        }
+    }
 }
 
 impl FromStr for Role {
@@ -59,3 +80,194 @@ impl Display for Role {
         write!(f, "{}", self.name())
     }
 }
+
+/// The finer roles the community splits [`Role`]s into, e.g. for party composition.
+///
+/// Each [`Job`] has a sub-role attached to it.
+///
+/// [`Job`]: ::jobs::Job
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum SubRole {
+    Tank,
+    /// Healers focused on restoring HP (e.g. White Mage).
+    PureHealer,
+    /// Healers focused on shielding HP (e.g. Scholar).
+    BarrierHealer,
+    Melee,
+    PhysicalRanged,
+    MagicalRanged,
+}
+
+impl SubRole {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [SubRole; 6] = SubRole::VARIANTS;
+
+    const VARIANTS: [SubRole; 6] = [
+        SubRole::Tank,
+        SubRole::PureHealer,
+        SubRole::BarrierHealer,
+        SubRole::Melee,
+        SubRole::PhysicalRanged,
+        SubRole::MagicalRanged,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            SubRole::Tank => "Tank",
+            SubRole::PureHealer => "PureHealer",
+            SubRole::BarrierHealer => "BarrierHealer",
+            SubRole::Melee => "Melee",
+            SubRole::PhysicalRanged => "PhysicalRanged",
+            SubRole::MagicalRanged => "MagicalRanged",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            SubRole::Tank => "Tank",
+            SubRole::PureHealer => "Pure Healer",
+            SubRole::BarrierHealer => "Barrier Healer",
+            SubRole::Melee => "Melee DPS",
+            SubRole::PhysicalRanged => "Physical Ranged DPS",
+            SubRole::MagicalRanged => "Magical Ranged DPS",
+        }
+    }
+
+    /// Returns the name of this sub-role in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            SubRole::Tank => ["Tank", "タンク", "Verteidiger", "Tank", "防护", "防護", "탱커"],
+            SubRole::PureHealer => [
+                "Pure Healer",
+                "ピュアヒーラー",
+                "Reiner Heiler",
+                "Soigneur pur",
+                "纯治疗",
+                "純治療",
+                "퓨어 힐러",
+            ],
+            SubRole::BarrierHealer => [
+                "Barrier Healer",
+                "バリアヒーラー",
+                "Barriere-Heiler",
+                "Soigneur à barrières",
+                "盾治疗",
+                "盾治療",
+                "보호막 힐러",
+            ],
+            SubRole::Melee => [
+                "Melee DPS",
+                "近接DPS",
+                "Nahkampf-Angreifer",
+                "DPS de mêlée",
+                "近战输出",
+                "近戰輸出",
+                "근거리 공격",
+            ],
+            SubRole::PhysicalRanged => [
+                "Physical Ranged DPS",
+                "遠隔物理DPS",
+                "Physischer Fernkampf-Angreifer",
+                "DPS physique à distance",
+                "远程物理输出",
+                "遠程物理輸出",
+                "원거리 물리 공격",
+            ],
+            SubRole::MagicalRanged => [
+                "Magical Ranged DPS",
+                "遠隔魔法DPS",
+                "Magischer Fernkampf-Angreifer",
+                "DPS magique à distance",
+                "远程魔法输出",
+                "遠程魔法輸出",
+                "원거리 마법 공격",
+            ],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns common community abbreviations of this sub-role's name in the given client language
+    /// (e.g. "远敏" for physical ranged DPS in Simplified Chinese).
+    pub fn abbreviations_in(&self, language: Language) -> &'static [&'static str] {
+        match language {
+            Language::ChineseSimplified => match *self {
+                SubRole::Tank => &["坦克", "T"],
+                SubRole::PureHealer => &["纯治", "纯奶"],
+                SubRole::BarrierHealer => &["盾治", "盾奶"],
+                SubRole::Melee => &["近战"],
+                SubRole::PhysicalRanged => &["远敏"],
+                SubRole::MagicalRanged => &["法系"],
+            },
+            Language::ChineseTraditional => match *self {
+                SubRole::Tank => &["坦克", "T"],
+                SubRole::PureHealer => &["純治", "純奶"],
+                SubRole::BarrierHealer => &["盾治", "盾奶"],
+                SubRole::Melee => &["近戰"],
+                SubRole::PhysicalRanged => &["遠敏"],
+                SubRole::MagicalRanged => &["法系"],
+            },
+            _ => &[],
+        }
+    }
+
+    /// Returns the coarse [`Role`] of this sub-role.
+    pub fn role(&self) -> Role {
+        match *self {
+            SubRole::Tank => Role::Tank,
+            SubRole::PureHealer | SubRole::BarrierHealer => Role::Healer,
+            SubRole::Melee | SubRole::PhysicalRanged | SubRole::MagicalRanged => Role::Dps,
+        }
+    }
+
+    /// Parses a string `s` as the name of a sub-role in one of the given client languages or one of
+    /// its community abbreviations (see [`SubRole::abbreviations_in`]).
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        languages::find_localized(
+            s,
+            languages,
+            &SubRole::VARIANTS,
+            SubRole::name_in,
+            SubRole::abbreviations_in,
+        )
+        .ok_or_else(|| UnknownVariant("SubRole", s.into()))
+    }
+}
+
+impl FromStr for SubRole {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts the name of the variant, the name with spaces between words and common English
+    /// short forms (e.g. "caster" for magical ranged DPS). The name in every client language is
+    /// accepted as well (see [`SubRole::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sub_role = match s.to_lowercase().as_str() {
+            "tank" => SubRole::Tank,
+            "purehealer" | "pure healer" | "pure" => SubRole::PureHealer,
+            "barrierhealer" | "barrier healer" | "shield healer" | "barrier" => SubRole::BarrierHealer,
+            "melee" | "melee dps" => SubRole::Melee,
+            "physicalranged" | "physical ranged" | "physical ranged dps" | "phys ranged" => {
+                SubRole::PhysicalRanged
+            }
+            "magicalranged" | "magical ranged" | "magical ranged dps" | "caster" => {
+                SubRole::MagicalRanged
+            }
+            _ => return SubRole::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(sub_role)
+    }
+}
+
+impl Display for SubRole {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
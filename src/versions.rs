@@ -0,0 +1,60 @@
+//! Game version types
+
+use errors::InvalidGameVersion;
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// A game patch, e.g. 6.18.
+///
+/// Patches are numbered `major.minor`, where `minor` is a two-digit number whose trailing zero is
+/// not written: patch 6.1 has a `minor` of 10, patch 6.18 has 18 and patch 6.05 has 5. Versions
+/// compare in release order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub struct GameVersion {
+    /// The expansion number (e.g. `6` for patch 6.18).
+    pub major: u8,
+    /// The two-digit patch number (e.g. `18` for patch 6.18, `10` for patch 6.1).
+    pub minor: u8,
+}
+
+impl GameVersion {
+    /// Creates a version from its expansion number and two-digit patch number.
+    ///
+    /// `GameVersion::new(6, 18)` is patch 6.18, `GameVersion::new(6, 10)` is patch 6.1.
+    pub const fn new(major: u8, minor: u8) -> GameVersion {
+        GameVersion { major, minor }
+    }
+}
+
+impl FromStr for GameVersion {
+    type Err = InvalidGameVersion;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// This accepts patch numbers as they are usually written (e.g. "6.18", "6.1", "6.05" and "7").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || InvalidGameVersion(s.into());
+
+        let mut parts = s.trim().splitn(2, '.');
+        let major = parts.next().and_then(|major| major.parse().ok()).ok_or_else(err)?;
+        let minor = match parts.next() {
+            None => 0,
+            Some(minor) if minor.len() == 1 => minor.parse::<u8>().map_err(|_| err())? * 10,
+            Some(minor) if minor.len() == 2 => minor.parse().map_err(|_| err())?,
+            Some(_) => return Err(err()),
+        };
+
+        Ok(GameVersion::new(major, minor))
+    }
+}
+
+impl Display for GameVersion {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self.minor % 10 {
+            0 => write!(f, "{}.{}", self.major, self.minor / 10),
+            _ => write!(f, "{}.{:02}", self.major, self.minor),
+        }
+    }
+}
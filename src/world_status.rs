@@ -0,0 +1,260 @@
+//! World status types
+
+use errors::UnknownVariant;
+use languages::{self, Language};
+use worlds::World;
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The population categories worlds are sorted into on the world status page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum WorldCategory {
+    Standard,
+    Preferred,
+    PreferredPlus,
+    Congested,
+    New,
+}
+
+impl WorldCategory {
+    #[cfg(feature = "all_const")]
+    pub const ALL: [WorldCategory; 5] = WorldCategory::VARIANTS;
+
+    const VARIANTS: [WorldCategory; 5] = [
+        WorldCategory::Standard,
+        WorldCategory::Preferred,
+        WorldCategory::PreferredPlus,
+        WorldCategory::Congested,
+        WorldCategory::New,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            WorldCategory::Standard => "Standard",
+            WorldCategory::Preferred => "Preferred",
+            WorldCategory::PreferredPlus => "PreferredPlus",
+            WorldCategory::Congested => "Congested",
+            WorldCategory::New => "New",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            WorldCategory::Standard => "Standard",
+            WorldCategory::Preferred => "Preferred",
+            WorldCategory::PreferredPlus => "Preferred+",
+            WorldCategory::Congested => "Congested",
+            WorldCategory::New => "New",
+        }
+    }
+
+    /// Returns the name of this category in the given client language.
+    pub fn name_in(&self, language: Language) -> &'static str {
+        let names = match *self {
+            WorldCategory::Standard => ["Standard", "標準", "Standard", "Standard", "标准", "標準", "일반"],
+            WorldCategory::Preferred => ["Preferred", "優遇", "Bevorzugt", "Privilégié", "优待", "優待", "우대"],
+            WorldCategory::PreferredPlus => [
+                "Preferred+",
+                "超優遇",
+                "Bevorzugt+",
+                "Privilégié+",
+                "超优待",
+                "超優待",
+                "초우대",
+            ],
+            WorldCategory::Congested => ["Congested", "混雑", "Überlastet", "Surchargé", "拥挤", "擁擠", "혼잡"],
+            WorldCategory::New => ["New", "新規", "Neu", "Nouveau", "新服", "新服", "신규"],
+        };
+
+        names[language as usize]
+    }
+
+    /// Returns whether new characters can usually be created on worlds in this category.
+    ///
+    /// Creation is closed on congested worlds, and sometimes on others (see [`WorldStatus`]).
+    pub fn allows_character_creation(&self) -> bool {
+        *self != WorldCategory::Congested
+    }
+
+    /// Parses a string `s` as the name of a category in one of the given client languages.
+    ///
+    /// This is case-insensitive.
+    pub fn from_str_in(s: &str, languages: &[Language]) -> Result<Self, UnknownVariant> {
+        let variants = &WorldCategory::VARIANTS;
+        languages::find_localized(s, languages, variants, WorldCategory::name_in, |_, _| &[])
+            .ok_or_else(|| UnknownVariant("WorldCategory", s.into()))
+    }
+}
+
+impl FromStr for WorldCategory {
+    type Err = UnknownVariant;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// The name in every client language is accepted as well (see [`WorldCategory::from_str_in`]).
+    ///
+    /// This is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let category = match s.to_lowercase().as_str() {
+            "standard" => WorldCategory::Standard,
+            "preferred" => WorldCategory::Preferred,
+            "preferredplus" | "preferred plus" | "preferred+" => WorldCategory::PreferredPlus,
+            "congested" => WorldCategory::Congested,
+            "new" => WorldCategory::New,
+            _ => return WorldCategory::from_str_in(s, &Language::VARIANTS),
+        };
+
+        Ok(category)
+    }
+}
+
+impl Display for WorldCategory {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The status of a [`World`]: its category and whether new characters can be created on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub struct WorldStatus {
+    pub category: WorldCategory,
+    pub character_creation_allowed: bool,
+}
+
+impl WorldStatus {
+    /// Creates a status with the usual character creation availability for `category`.
+    pub fn new(category: WorldCategory) -> WorldStatus {
+        WorldStatus {
+            category,
+            character_creation_allowed: category.allows_character_creation(),
+        }
+    }
+}
+
+impl Default for WorldStatus {
+    fn default() -> Self {
+        WorldStatus::new(WorldCategory::Standard)
+    }
+}
+
+/// The statuses of worlds that are not [`WorldCategory::Standard`] in the snapshot shipped with this
+/// crate, as `(world, category, character creation allowed)`.
+const SNAPSHOT: &[(World, WorldCategory, bool)] = &[
+    // Aether
+    (World::Adamantoise, WorldCategory::Congested, false),
+    (World::Gilgamesh, WorldCategory::Congested, false),
+    // Crystal
+    (World::Balmung, WorldCategory::Congested, false),
+    // Dynamis
+    (World::Cuchulainn, WorldCategory::Preferred, true),
+    (World::Golem, WorldCategory::Preferred, true),
+    (World::Kraken, WorldCategory::Preferred, true),
+    (World::Rafflesia, WorldCategory::Preferred, true),
+    // Light
+    (World::Alpha, WorldCategory::Preferred, true),
+    (World::Raiden, WorldCategory::Preferred, true),
+    // Shadow
+    (World::Innocence, WorldCategory::New, true),
+    (World::Pixie, WorldCategory::New, true),
+    (World::Titania, WorldCategory::New, true),
+    (World::Tycoon, WorldCategory::New, true),
+    // Elemental
+    (World::Tonberry, WorldCategory::Congested, false),
+    // Gaia
+    (World::Ifrit, WorldCategory::Congested, false),
+    // Mana
+    (World::Masamune, WorldCategory::Congested, false),
+    // 陆行鸟
+    (World::红玉海, WorldCategory::Congested, false),
+    (World::神意之地, WorldCategory::Congested, false),
+    // 莫古力
+    (World::拂晓之间, WorldCategory::Congested, false),
+    // 豆豆柴
+    (World::亚马乌罗提, WorldCategory::New, true),
+];
+
+/// A table of [`WorldStatus`]es, one per [`World`].
+///
+/// World statuses change between patches, so the [snapshot] shipped with this crate will drift out
+/// of date. Entries can be overridden at runtime with [`set`] or [`extend`]; with feature
+/// `with_serde`, a table (de)serializes as a map from world to status, so overrides can be loaded
+/// from a local JSON or TOML file:
+///
+/// ```json
+/// { "Adamantoise": { "category": "Standard", "character_creation_allowed": true } }
+/// ```
+///
+/// Worlds without an entry are [`WorldCategory::Standard`] and allow character creation.
+///
+/// [snapshot]: WorldStatusTable::snapshot
+/// [`set`]: WorldStatusTable::set
+/// [`extend`]: WorldStatusTable::extend
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "with_serde", serde(transparent))]
+pub struct WorldStatusTable {
+    statuses: HashMap<World, WorldStatus>,
+}
+
+impl WorldStatusTable {
+    /// Creates an empty table, in which every world is standard.
+    pub fn new() -> WorldStatusTable {
+        WorldStatusTable::default()
+    }
+
+    /// Returns the snapshot of world statuses shipped with this crate.
+    pub fn snapshot() -> WorldStatusTable {
+        let statuses = SNAPSHOT
+            .iter()
+            .map(|&(world, category, character_creation_allowed)| {
+                let status = WorldStatus {
+                    category,
+                    character_creation_allowed,
+                };
+                (world, status)
+            })
+            .collect();
+
+        WorldStatusTable { statuses }
+    }
+
+    /// Returns the status of `world`.
+    pub fn get(&self, world: World) -> WorldStatus {
+        self.statuses.get(&world).cloned().unwrap_or_default()
+    }
+
+    /// Sets the status of `world`, returning its previous entry, if any.
+    pub fn set(&mut self, world: World, status: WorldStatus) -> Option<WorldStatus> {
+        self.statuses.insert(world, status)
+    }
+
+    /// Overrides entries of this table with every entry of `overrides`.
+    pub fn extend(&mut self, overrides: WorldStatusTable) {
+        self.statuses.extend(overrides.statuses);
+    }
+
+    /// Returns the worlds in this table with the given category.
+    pub fn worlds_in(&self, category: WorldCategory) -> Vec<World> {
+        World::VARIANTS
+            .iter()
+            .cloned()
+            .filter(|&world| self.get(world).category == category)
+            .collect()
+    }
+}
+
+/// Returns the status of `world` in the snapshot shipped with this crate.
+pub(crate) fn snapshot_status(world: World) -> WorldStatus {
+    SNAPSHOT
+        .iter()
+        .find(|&&(w, _, _)| w == world)
+        .map(|&(_, category, character_creation_allowed)| WorldStatus {
+            category,
+            character_creation_allowed,
+        })
+        .unwrap_or_default()
+}
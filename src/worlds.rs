@@ -1,16 +1,81 @@
 //! World types
 
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
 
 #[cfg(feature = "data_centers")]
 use data_centers::DataCenter;
-use errors::UnknownVariant;
+use errors::{UnknownId, UnknownVariant};
+use languages::Language;
+#[cfg(feature = "data_centers")]
+use versions::GameVersion;
+#[cfg(feature = "world_status")]
+use world_status::{self, WorldStatus};
+
+/// Recorded data center changes, as `(world, version, data center)`: from `version` onwards,
+/// `world` is on `data center`.
+///
+/// Entries are in release order. A world with entries did not exist before its first entry; the
+/// history of a world without entries is not recorded.
+#[cfg(feature = "data_centers")]
+const DATA_CENTER_HISTORY: &[(World, GameVersion, DataCenter)] = &[
+    // Japanese worlds before Meteor split off Elemental, Gaia and Mana
+    (World::Ramuh, GameVersion::new(2, 0), DataCenter::Elemental),
+    (World::Unicorn, GameVersion::new(2, 0), DataCenter::Elemental),
+    (World::Valefor, GameVersion::new(2, 0), DataCenter::Gaia),
+    (World::Yojimbo, GameVersion::new(2, 0), DataCenter::Gaia),
+    (World::Zeromus, GameVersion::new(2, 0), DataCenter::Gaia),
+    (World::Belias, GameVersion::new(2, 0), DataCenter::Mana),
+    (World::Mandragora, GameVersion::new(2, 0), DataCenter::Mana),
+    (World::Shinryu, GameVersion::new(2, 0), DataCenter::Mana),
+    // 6.08: Materia opens for Oceania
+    (World::Bismarck, GameVersion::new(6, 8), DataCenter::Materia),
+    (World::Ravana, GameVersion::new(6, 8), DataCenter::Materia),
+    (World::Sephirot, GameVersion::new(6, 8), DataCenter::Materia),
+    (World::Sophia, GameVersion::new(6, 8), DataCenter::Materia),
+    (World::Zurvan, GameVersion::new(6, 8), DataCenter::Materia),
+    // 6.18: Meteor and Dynamis open, Chaos and Light gain new worlds
+    (World::Belias, GameVersion::new(6, 18), DataCenter::Meteor),
+    (World::Mandragora, GameVersion::new(6, 18), DataCenter::Meteor),
+    (World::Ramuh, GameVersion::new(6, 18), DataCenter::Meteor),
+    (World::Shinryu, GameVersion::new(6, 18), DataCenter::Meteor),
+    (World::Unicorn, GameVersion::new(6, 18), DataCenter::Meteor),
+    (World::Valefor, GameVersion::new(6, 18), DataCenter::Meteor),
+    (World::Yojimbo, GameVersion::new(6, 18), DataCenter::Meteor),
+    (World::Zeromus, GameVersion::new(6, 18), DataCenter::Meteor),
+    (World::Halicarnassus, GameVersion::new(6, 18), DataCenter::Dynamis),
+    (World::Maduin, GameVersion::new(6, 18), DataCenter::Dynamis),
+    (World::Marilith, GameVersion::new(6, 18), DataCenter::Dynamis),
+    (World::Seraph, GameVersion::new(6, 18), DataCenter::Dynamis),
+    (World::Phantom, GameVersion::new(6, 18), DataCenter::Chaos),
+    (World::Sagittarius, GameVersion::new(6, 18), DataCenter::Chaos),
+    (World::Alpha, GameVersion::new(6, 18), DataCenter::Light),
+    (World::Raiden, GameVersion::new(6, 18), DataCenter::Light),
+    // 6.2 (Chinese service): 豆豆柴 opens
+    (World::红茶川, GameVersion::new(6, 20), DataCenter::豆豆柴),
+    (World::伊修加德, GameVersion::new(6, 20), DataCenter::豆豆柴),
+    (World::太阳海岸, GameVersion::new(6, 20), DataCenter::豆豆柴),
+    (World::银泪湖, GameVersion::new(6, 20), DataCenter::豆豆柴),
+    (World::水晶塔, GameVersion::new(6, 20), DataCenter::豆豆柴),
+    // 6.5: Dynamis gains new worlds
+    (World::Cuchulainn, GameVersion::new(6, 50), DataCenter::Dynamis),
+    (World::Golem, GameVersion::new(6, 50), DataCenter::Dynamis),
+    (World::Kraken, GameVersion::new(6, 50), DataCenter::Dynamis),
+    (World::Rafflesia, GameVersion::new(6, 50), DataCenter::Dynamis),
+    // 7.0 (Chinese service): 亚马乌罗提 opens on 豆豆柴
+    (World::亚马乌罗提, GameVersion::new(7, 0), DataCenter::豆豆柴),
+    // 7.2: Shadow opens for Europe
+    (World::Innocence, GameVersion::new(7, 20), DataCenter::Shadow),
+    (World::Pixie, GameVersion::new(7, 20), DataCenter::Shadow),
+    (World::Titania, GameVersion::new(7, 20), DataCenter::Shadow),
+    (World::Tycoon, GameVersion::new(7, 20), DataCenter::Shadow),
+];
 
 /// The worlds, sometimes called servers, in the game.
 ///
 /// Each [`DataCenter`] has multiple worlds attached to it.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
 pub enum World {
     // Aether
@@ -183,7 +248,10 @@ pub enum World {
 
 impl World {
     #[cfg(feature = "all_const")]
-    pub const ALL: [World; 131] = [
+    pub const ALL: [World; 131] = World::VARIANTS;
+
+    #[cfg(any(feature = "all_const", feature = "world_status"))]
+    pub(crate) const VARIANTS: [World; 131] = [
         // Aether
         World::Adamantoise,
         World::Cactuar,
@@ -512,6 +580,370 @@ impl World {
         self.as_str()
     }
 
+    /// Returns the name of this world in the given client language.
+    ///
+    /// World names are not translated; every client shows the same name.
+    pub fn name_in(&self, _language: Language) -> &'static str {
+        self.name()
+    }
+
+    /// Returns the row ID of this world in the game's `World` sheet.
+    pub fn id(&self) -> u16 {
+        match *self {
+            // Aether
+            World::Adamantoise => 73,
+            World::Cactuar => 79,
+            World::Faerie => 54,
+            World::Gilgamesh => 63,
+            World::Jenova => 40,
+            World::Midgardsormr => 65,
+            World::Sargatanas => 99,
+            World::Siren => 57,
+
+            // Chaos
+            World::Cerberus => 80,
+            World::Louisoix => 83,
+            World::Moogle => 71,
+            World::Omega => 39,
+            World::Phantom => 401,
+            World::Ragnarok => 97,
+            World::Sagittarius => 400,
+            World::Spriggan => 85,
+
+            // Crystal
+            World::Balmung => 91,
+            World::Brynhildr => 34,
+            World::Coeurl => 74,
+            World::Diabolos => 62,
+            World::Goblin => 81,
+            World::Malboro => 75,
+            World::Mateus => 37,
+            World::Zalera => 41,
+
+            // Elemental
+            World::Aegis => 90,
+            World::Atomos => 68,
+            World::Carbuncle => 45,
+            World::Garuda => 58,
+            World::Gungnir => 94,
+            World::Kujata => 49,
+            World::Tonberry => 72,
+            World::Typhon => 50,
+
+            // Gaia
+            World::Alexander => 43,
+            World::Bahamut => 69,
+            World::Durandal => 92,
+            World::Fenrir => 46,
+            World::Ifrit => 59,
+            World::Ridill => 98,
+            World::Tiamat => 76,
+            World::Ultima => 51,
+
+            // Light
+            World::Alpha => 402,
+            World::Lich => 36,
+            World::Odin => 66,
+            World::Phoenix => 56,
+            World::Raiden => 403,
+            World::Shiva => 67,
+            World::Twintania => 33,
+            World::Zodiark => 42,
+
+            // Mana
+            World::Anima => 44,
+            World::Asura => 23,
+            World::Chocobo => 70,
+            World::Hades => 47,
+            World::Ixion => 48,
+            World::Masamune => 96,
+            World::Pandaemonium => 28,
+            World::Titan => 61,
+
+            // Primal
+            World::Behemoth => 78,
+            World::Excalibur => 93,
+            World::Exodus => 53,
+            World::Famfrit => 35,
+            World::Hyperion => 95,
+            World::Lamia => 55,
+            World::Leviathan => 64,
+            World::Ultros => 77,
+
+            // Materia
+            World::Bismarck => 22,
+            World::Ravana => 21,
+            World::Sephirot => 86,
+            World::Sophia => 87,
+            World::Zurvan => 88,
+
+            // Meteor
+            World::Belias => 24,
+            World::Mandragora => 82,
+            World::Ramuh => 60,
+            World::Shinryu => 29,
+            World::Unicorn => 30,
+            World::Valefor => 52,
+            World::Yojimbo => 31,
+            World::Zeromus => 32,
+
+            // Dynamis
+            World::Cuchulainn => 408,
+            World::Golem => 411,
+            World::Halicarnassus => 406,
+            World::Kraken => 409,
+            World::Maduin => 407,
+            World::Marilith => 404,
+            World::Rafflesia => 410,
+            World::Seraph => 405,
+
+            // Shadow
+            World::Innocence => 412,
+            World::Pixie => 413,
+            World::Titania => 414,
+            World::Tycoon => 415,
+
+            // 陆行鸟
+            World::晨曦王座 => 1175,
+            World::沃仙曦染 => 1174,
+            World::宇宙和音 => 1173,
+            World::红玉海 => 1167,
+            World::萌芽池 => 1060,
+            World::神意之地 => 1081,
+            World::幻影群岛 => 1044,
+            World::拉诺西亚 => 1042,
+
+            // 莫古力
+            World::拂晓之间 => 1121,
+            World::龙巢神殿 => 1166,
+            World::旅人栈桥 => 1113,
+            World::白金幻象 => 1076,
+            World::梦羽宝境 => 1176,
+            World::神拳痕 => 1171,
+            World::潮风亭 => 1170,
+            World::白银乡 => 1172,
+
+            // 猫小胖
+            World::琥珀原 => 1179,
+            World::柔风海湾 => 1178,
+            World::海猫茶屋 => 1177,
+            World::延夏 => 1169,
+            World::静语庄园 => 1106,
+            World::摩杜纳 => 1045,
+            World::紫水栈桥 => 1043,
+
+            // 豆豆柴
+            World::红茶川 => 1201,
+            World::伊修加德 => 1186,
+            World::太阳海岸 => 1180,
+            World::银泪湖 => 1183,
+            World::水晶塔 => 1192,
+            World::亚马乌罗提 => 1200,
+
+            // 陸行鳥
+            World::伊弗利特 => 4028,
+            World::迦樓羅 => 4029,
+            World::利維坦 => 4030,
+            World::鳳凰 => 4031,
+            World::奧汀 => 4032,
+            World::巴哈姆特 => 4033,
+            World::拉姆 => 4034,
+            World::泰坦 => 4035,
+
+            // Korean
+            World::펜리르 => 2080,
+            World::카벙클 => 2075,
+            World::톤베리 => 2078,
+            World::초코보 => 2076,
+            World::모그리 => 2077,
+        }
+    }
+
+    /// Returns the world with the given row ID in the game's `World` sheet, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        let world = match id {
+            // Aether
+            73 => World::Adamantoise,
+            79 => World::Cactuar,
+            54 => World::Faerie,
+            63 => World::Gilgamesh,
+            40 => World::Jenova,
+            65 => World::Midgardsormr,
+            99 => World::Sargatanas,
+            57 => World::Siren,
+
+            // Chaos
+            80 => World::Cerberus,
+            83 => World::Louisoix,
+            71 => World::Moogle,
+            39 => World::Omega,
+            401 => World::Phantom,
+            97 => World::Ragnarok,
+            400 => World::Sagittarius,
+            85 => World::Spriggan,
+
+            // Crystal
+            91 => World::Balmung,
+            34 => World::Brynhildr,
+            74 => World::Coeurl,
+            62 => World::Diabolos,
+            81 => World::Goblin,
+            75 => World::Malboro,
+            37 => World::Mateus,
+            41 => World::Zalera,
+
+            // Elemental
+            90 => World::Aegis,
+            68 => World::Atomos,
+            45 => World::Carbuncle,
+            58 => World::Garuda,
+            94 => World::Gungnir,
+            49 => World::Kujata,
+            72 => World::Tonberry,
+            50 => World::Typhon,
+
+            // Gaia
+            43 => World::Alexander,
+            69 => World::Bahamut,
+            92 => World::Durandal,
+            46 => World::Fenrir,
+            59 => World::Ifrit,
+            98 => World::Ridill,
+            76 => World::Tiamat,
+            51 => World::Ultima,
+
+            // Light
+            402 => World::Alpha,
+            36 => World::Lich,
+            66 => World::Odin,
+            56 => World::Phoenix,
+            403 => World::Raiden,
+            67 => World::Shiva,
+            33 => World::Twintania,
+            42 => World::Zodiark,
+
+            // Mana
+            44 => World::Anima,
+            23 => World::Asura,
+            70 => World::Chocobo,
+            47 => World::Hades,
+            48 => World::Ixion,
+            96 => World::Masamune,
+            28 => World::Pandaemonium,
+            61 => World::Titan,
+
+            // Primal
+            78 => World::Behemoth,
+            93 => World::Excalibur,
+            53 => World::Exodus,
+            35 => World::Famfrit,
+            95 => World::Hyperion,
+            55 => World::Lamia,
+            64 => World::Leviathan,
+            77 => World::Ultros,
+
+            // Materia
+            22 => World::Bismarck,
+            21 => World::Ravana,
+            86 => World::Sephirot,
+            87 => World::Sophia,
+            88 => World::Zurvan,
+
+            // Meteor
+            24 => World::Belias,
+            82 => World::Mandragora,
+            60 => World::Ramuh,
+            29 => World::Shinryu,
+            30 => World::Unicorn,
+            52 => World::Valefor,
+            31 => World::Yojimbo,
+            32 => World::Zeromus,
+
+            // Dynamis
+            408 => World::Cuchulainn,
+            411 => World::Golem,
+            406 => World::Halicarnassus,
+            409 => World::Kraken,
+            407 => World::Maduin,
+            404 => World::Marilith,
+            410 => World::Rafflesia,
+            405 => World::Seraph,
+
+            // Shadow
+            412 => World::Innocence,
+            413 => World::Pixie,
+            414 => World::Titania,
+            415 => World::Tycoon,
+
+            // 陆行鸟
+            1175 => World::晨曦王座,
+            1174 => World::沃仙曦染,
+            1173 => World::宇宙和音,
+            1167 => World::红玉海,
+            1060 => World::萌芽池,
+            1081 => World::神意之地,
+            1044 => World::幻影群岛,
+            1042 => World::拉诺西亚,
+
+            // 莫古力
+            1121 => World::拂晓之间,
+            1166 => World::龙巢神殿,
+            1113 => World::旅人栈桥,
+            1076 => World::白金幻象,
+            1176 => World::梦羽宝境,
+            1171 => World::神拳痕,
+            1170 => World::潮风亭,
+            1172 => World::白银乡,
+
+            // 猫小胖
+            1179 => World::琥珀原,
+            1178 => World::柔风海湾,
+            1177 => World::海猫茶屋,
+            1169 => World::延夏,
+            1106 => World::静语庄园,
+            1045 => World::摩杜纳,
+            1043 => World::紫水栈桥,
+
+            // 豆豆柴
+            1201 => World::红茶川,
+            1186 => World::伊修加德,
+            1180 => World::太阳海岸,
+            1183 => World::银泪湖,
+            1192 => World::水晶塔,
+            1200 => World::亚马乌罗提,
+
+            // 陸行鳥
+            4028 => World::伊弗利特,
+            4029 => World::迦樓羅,
+            4030 => World::利維坦,
+            4031 => World::鳳凰,
+            4032 => World::奧汀,
+            4033 => World::巴哈姆特,
+            4034 => World::拉姆,
+            4035 => World::泰坦,
+
+            // Korean
+            2080 => World::펜리르,
+            2075 => World::카벙클,
+            2078 => World::톤베리,
+            2076 => World::초코보,
+            2077 => World::모그리,
+            _ => return None,
+        };
+
+        Some(world)
+    }
+
+    /// Returns the status of this world in the snapshot shipped with this crate.
+    ///
+    /// The snapshot drifts out of date between releases; see [`WorldStatusTable`] to override it.
+    ///
+    /// [`WorldStatusTable`]: ::world_status::WorldStatusTable
+    #[cfg(feature = "world_status")]
+    pub fn status(&self) -> WorldStatus {
+        world_status::snapshot_status(*self)
+    }
+
     /// Returns the [`DataCenter`] this world is on.
     #[cfg(feature = "data_centers")]
     pub fn data_center(&self) -> DataCenter {
@@ -677,6 +1109,85 @@ impl World {
             } // Korean
         }
     }
+
+    /// Returns the [`DataCenter`] this world was on in the given game version.
+    ///
+    /// Returns `None` if the world had not opened yet in that version, or if the world's history is
+    /// not recorded. Only worlds that opened after launch or moved between data centers have a
+    /// recorded history; use [`World::data_center`] for the others.
+    ///
+    /// Versions of the Chinese, Taiwanese and Korean services refer to the patch running there at
+    /// the time, which is released later than the same patch on the global service.
+    #[cfg(feature = "data_centers")]
+    pub fn data_center_at(&self, version: GameVersion) -> Option<DataCenter> {
+        DATA_CENTER_HISTORY
+            .iter()
+            .filter(|&&(world, _, _)| world == *self)
+            .take_while(|&&(_, since, _)| since <= version)
+            .last()
+            .map(|&(_, _, data_center)| data_center)
+    }
+}
+
+/// How a character can reach one [`World`] from another.
+///
+/// Returned by [`World::can_travel_to`].
+#[cfg(feature = "regions")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(Serialize, Deserialize))]
+pub enum TravelKind {
+    /// Both worlds are the same world.
+    SameWorld,
+    /// The worlds share a data center and can be reached with World Visit.
+    WorldVisit,
+    /// The worlds are on different data centers in the same region and can be reached with Data
+    /// Center Travel (超域旅行 on the Chinese service).
+    DataCenterTravel,
+    /// The worlds are in different regions run by the same publisher and can be reached with Data
+    /// Center Travel between regions.
+    RegionTravel,
+    /// The worlds are run by different publishers and cannot be reached from each other.
+    Impossible,
+}
+
+#[cfg(feature = "regions")]
+impl TravelKind {
+    /// Returns whether a character can reach the other world at all.
+    pub fn is_possible(&self) -> bool {
+        *self != TravelKind::Impossible
+    }
+}
+
+#[cfg(feature = "regions")]
+impl World {
+    /// Returns how a character on this world can reach `other`.
+    ///
+    /// Travel is possible between any two worlds run by the same [`Publisher`]: the global service
+    /// allows Data Center Travel between North America, Europe, Japan and Oceania, and the Chinese
+    /// service allows 超域旅行 between all of its data centers. The Taiwanese and Korean services
+    /// each have a single data center, so only World Visit is available there.
+    ///
+    /// The global service's rules are intentionally collapsed: travel between any two of its
+    /// regions (logical data centers in different physical locations) is reported as
+    /// [`TravelKind::RegionTravel`], and per-world restrictions such as congested or newly opened
+    /// worlds refusing arrivals are not modelled.
+    ///
+    /// [`Publisher`]: ::regions::Publisher
+    pub fn can_travel_to(&self, other: &World) -> TravelKind {
+        let (from, to) = (self.data_center(), other.data_center());
+
+        if self == other {
+            TravelKind::SameWorld
+        } else if from == to {
+            TravelKind::WorldVisit
+        } else if from.region() == to.region() {
+            TravelKind::DataCenterTravel
+        } else if from.region().publisher() == to.region().publisher() {
+            TravelKind::RegionTravel
+        } else {
+            TravelKind::Impossible
+        }
+    }
 }
 
 impl FromStr for World {
@@ -861,6 +1372,14 @@ impl FromStr for World {
     }
 }
 
+impl TryFrom<u16> for World {
+    type Error = UnknownId;
+
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        World::from_id(id).ok_or(UnknownId("World", id))
+    }
+}
+
 impl Display for World {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         write!(f, "{}", self.name())
@@ -0,0 +1,66 @@
+#![cfg(feature = "calendar")]
+
+extern crate ffxiv_types_cn;
+
+use ffxiv_types_cn::{EorzeaTime, Moon};
+
+#[test]
+fn unix_epoch_is_the_eorzean_epoch() {
+    let time = EorzeaTime::from_unix(0);
+    assert_eq!((time.year(), time.moon(), time.sun()), (1, Moon::FirstAstral, 1));
+    assert_eq!((time.bell(), time.minute(), time.second()), (0, 0, 0));
+}
+
+#[test]
+fn a_bell_lasts_175_seconds() {
+    assert_eq!(EorzeaTime::from_unix(174).bell(), 0);
+    assert_eq!(EorzeaTime::from_unix(175).bell(), 1);
+    assert_eq!(EorzeaTime::from_unix(175 * 24).sun(), 2);
+}
+
+#[test]
+fn unix_timestamps_round_trip() {
+    for &millis in &[0, 1, 1_000, 1_700_000_000_000, 1_700_000_123_456] {
+        let time = EorzeaTime::from_unix_millis(millis);
+        let start = time.to_unix_millis().unwrap();
+        assert!(start <= millis, "{}", millis);
+        assert_eq!(EorzeaTime::from_unix_millis(start), time);
+    }
+
+    let time = EorzeaTime::new(1000, Moon::SixthUmbral, 32, 23, 59).unwrap();
+    assert_eq!(EorzeaTime::from_unix_millis(time.to_unix_millis().unwrap()), time);
+    assert!(EorzeaTime::from_unix(time.to_unix()) <= time);
+}
+
+#[test]
+fn far_times_do_not_overflow() {
+    let time = EorzeaTime::from_timestamp(u64::MAX);
+    assert_eq!(time.to_unix_millis(), None);
+    assert_eq!(time.to_unix(), (u128::from(u64::MAX) * 175 / 3600) as u64);
+}
+
+#[test]
+fn new_rejects_out_of_range_components() {
+    assert!(EorzeaTime::new(0, Moon::FirstAstral, 1, 0, 0).is_none());
+    assert!(EorzeaTime::new(1, Moon::FirstAstral, 0, 0, 0).is_none());
+    assert!(EorzeaTime::new(1, Moon::FirstAstral, 33, 0, 0).is_none());
+    assert!(EorzeaTime::new(1, Moon::FirstAstral, 1, 24, 0).is_none());
+    assert!(EorzeaTime::new(1, Moon::FirstAstral, 1, 0, 60).is_none());
+    assert!(EorzeaTime::new(u64::MAX, Moon::FirstAstral, 1, 0, 0).is_none());
+}
+
+#[cfg(feature = "all_const")]
+#[test]
+fn namedays_parse_in_every_language() {
+    use ffxiv_types_cn::{Language, Nameday};
+
+    let nameday = Nameday::new(Moon::ThirdUmbral, 21).unwrap();
+    for &language in Language::ALL.iter() {
+        let formatted = nameday.format_in(language);
+        assert_eq!(formatted.parse::<Nameday>().ok(), Some(nameday), "{}", formatted);
+    }
+
+    assert!("33rd Sun of the 1st Astral Moon".parse::<Nameday>().is_err());
+    assert!("0th Sun of the 1st Astral Moon".parse::<Nameday>().is_err());
+    assert!(Nameday::new(Moon::FirstAstral, 0).is_none());
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "formulas")]
+
+extern crate ffxiv_types_cn;
+
+use ffxiv_types_cn::jobs::{Class, Job};
+use ffxiv_types_cn::{Formulas, Level, Stats};
+
+fn level(level: u8) -> Level {
+    Level::new(level).unwrap()
+}
+
+#[test]
+fn base_stats_at_level_100() {
+    let formulas = Formulas::new(Job::Ninja, level(100)).unwrap();
+    let modifiers = formulas.level_modifiers();
+    assert_eq!((modifiers.main, modifiers.sub, modifiers.div), (440, 420, 2780));
+
+    assert_eq!(formulas.critical_hit_rate(420), 50);
+    assert_eq!(formulas.critical_hit_multiplier(420), 1400);
+    assert_eq!(formulas.direct_hit_rate(420), 0);
+    assert_eq!(formulas.determination_multiplier(440), 1000);
+    assert_eq!(formulas.tenacity_multiplier(420), 1000);
+    assert_eq!(formulas.attack_power_multiplier(440), 100);
+    assert_eq!(formulas.gcd(2500, 420, 0), 2500);
+
+    // Stats below their base value count as the base value.
+    assert_eq!(formulas.critical_hit_rate(0), 50);
+    assert_eq!(formulas.gcd(2500, 0, 0), 2500);
+}
+
+#[test]
+fn substats_at_level_100() {
+    let formulas = Formulas::new(Job::Ninja, level(100)).unwrap();
+
+    // floor(200 * (3000 - 420) / 2780) = 185
+    assert_eq!(formulas.critical_hit_rate(3000), 235);
+    assert_eq!(formulas.critical_hit_multiplier(3000), 1585);
+    // floor(550 * (1420 - 420) / 2780) = 197
+    assert_eq!(formulas.direct_hit_rate(1420), 197);
+    // floor(140 * (2440 - 440) / 2780) = 100
+    assert_eq!(formulas.determination_multiplier(2440), 1100);
+    // floor(2500 * (1000 - floor(130 * (1000 - 420) / 2780)) / 1000) = 2432, rounded down to 2430
+    assert_eq!(formulas.gcd(2500, 1000, 0), 2430);
+    assert_eq!(formulas.gcd(2500, 420, 13), 2170);
+    // floor(237 * (4840 - 440) / 440) + 100 = 2470
+    assert_eq!(formulas.attack_power_multiplier(4840), 2470);
+    // floor(440 * 110 / 1000) + 140 = 188
+    assert_eq!(formulas.weapon_damage_multiplier(140), 188);
+}
+
+#[test]
+fn tanks_use_tenacity_and_their_own_attack_power() {
+    let formulas = Formulas::new(Job::Paladin, level(100)).unwrap();
+
+    // floor(112 * (1420 - 420) / 2780) = 40
+    assert_eq!(formulas.tenacity_multiplier(1420), 1040);
+    // floor(190 * (4840 - 440) / 440) + 100 = 2000
+    assert_eq!(formulas.attack_power_multiplier(4840), 2000);
+
+    let ninja = Formulas::new(Job::Ninja, level(100)).unwrap();
+    assert_eq!(ninja.tenacity_multiplier(1420), 1000);
+}
+
+#[test]
+fn formulas_only_exist_at_level_caps() {
+    assert!(Formulas::new(Job::Ninja, level(99)).is_none());
+    assert!(Formulas::new(Class::Rogue, level(50)).is_some());
+    for &cap in &[50, 60, 70, 80, 90, 100] {
+        assert!(Formulas::new(Job::WhiteMage, level(cap)).is_some(), "{}", cap);
+    }
+}
+
+#[test]
+fn damage_grows_with_stats() {
+    let formulas = Formulas::new(Job::BlackMage, level(100)).unwrap();
+    let stats = Stats {
+        weapon_damage: 146,
+        main_attribute: 4900,
+        critical_hit: 2500,
+        direct_hit: 1500,
+        determination: 2200,
+        speed: 600,
+        tenacity: 420,
+    };
+
+    let damage = formulas.damage(300, &stats);
+    assert!(damage > 0);
+    assert!(formulas.expected_damage(300, &stats) > f64::from(damage));
+
+    let stronger = Stats {
+        main_attribute: 5000,
+        ..stats
+    };
+    assert!(formulas.damage(300, &stronger) > damage);
+}
+
+#[test]
+fn damage_rounds_in_the_game_order() {
+    let formulas = Formulas::new(Job::Paladin, level(100)).unwrap();
+    let stats = Stats {
+        weapon_damage: 146,
+        main_attribute: 4840,
+        critical_hit: 420,
+        direct_hit: 420,
+        determination: 2200,
+        speed: 420,
+        tenacity: 1420,
+    };
+
+    let ap = formulas.attack_power_multiplier(4840);
+    let det = formulas.determination_multiplier(2200);
+    let damage = 300 * ap * det / 100 / 1000;
+    let damage = damage * formulas.tenacity_multiplier(1420) / 1000;
+    let damage = damage * formulas.weapon_damage_multiplier(146) / 100;
+    let damage = damage * formulas.trait_multiplier() / 100;
+
+    assert_eq!(formulas.damage(300, &stats), damage);
+}
+
+#[test]
+fn huge_stats_do_not_overflow() {
+    for &job in &[Job::Paladin, Job::Ninja, Job::BlackMage] {
+        let formulas = Formulas::new(job, level(50)).unwrap();
+        let stats = Stats {
+            weapon_damage: u32::MAX,
+            main_attribute: u32::MAX,
+            critical_hit: u32::MAX,
+            direct_hit: u32::MAX,
+            determination: u32::MAX,
+            speed: u32::MAX,
+            tenacity: u32::MAX,
+        };
+
+        assert_eq!(formulas.gcd(u32::MAX, u32::MAX, 0), 0);
+        assert_eq!(formulas.gcd(u32::MAX, 0, 0), u32::MAX / 10 * 10);
+        assert_eq!(formulas.direct_hit_rate(u32::MAX), u32::MAX);
+        assert!(formulas.attack_power_multiplier(u32::MAX) > 1_000_000_000);
+        assert_eq!(formulas.damage(u32::MAX, &stats), u32::MAX);
+        assert!(formulas.expected_damage(u32::MAX, &stats).is_finite());
+    }
+}
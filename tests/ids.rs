@@ -0,0 +1,50 @@
+#![cfg(feature = "all_const")]
+
+extern crate ffxiv_types_cn;
+
+#[cfg(feature = "combat_jobs")]
+use ffxiv_types_cn::jobs::{Class, Job};
+#[cfg(feature = "non_combat_jobs")]
+use ffxiv_types_cn::jobs::NonCombatJob;
+#[cfg(feature = "data_centers")]
+use ffxiv_types_cn::DataCenter;
+#[cfg(feature = "grand_companies")]
+use ffxiv_types_cn::GrandCompany;
+#[cfg(feature = "worlds")]
+use ffxiv_types_cn::World;
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+/// Checks that IDs are unique and that every ID converts back to its variant.
+macro_rules! id_round_trip {
+    ($test:ident, $ty:ident) => {
+        #[test]
+        fn $test() {
+            let mut ids = HashSet::new();
+            for &variant in $ty::ALL.iter() {
+                let id = variant.id();
+                assert!(ids.insert(id), "duplicate ID {}", id);
+                assert_eq!($ty::from_id(id), Some(variant));
+                assert_eq!($ty::try_from(id).ok(), Some(variant));
+            }
+
+            let unknown = (0..=u16::MAX).find(|id| !ids.contains(id)).unwrap();
+            let err = $ty::try_from(unknown).err().unwrap();
+            assert_eq!(err.1, unknown);
+        }
+    };
+}
+
+#[cfg(feature = "worlds")]
+id_round_trip!(world_ids, World);
+#[cfg(feature = "data_centers")]
+id_round_trip!(data_center_ids, DataCenter);
+#[cfg(feature = "combat_jobs")]
+id_round_trip!(job_ids, Job);
+#[cfg(feature = "combat_jobs")]
+id_round_trip!(class_ids, Class);
+#[cfg(feature = "non_combat_jobs")]
+id_round_trip!(non_combat_job_ids, NonCombatJob);
+#[cfg(feature = "grand_companies")]
+id_round_trip!(grand_company_ids, GrandCompany);
@@ -0,0 +1,81 @@
+#![cfg(all(feature = "combat_jobs", feature = "all_const"))]
+
+extern crate ffxiv_types_cn;
+
+use ffxiv_types_cn::jobs::Job;
+
+#[cfg(all(feature = "attributes", feature = "gear"))]
+#[test]
+fn gear_matches_the_primary_attribute() {
+    for &job in Job::ALL.iter() {
+        let category = job.gear_category();
+        assert_eq!(category.attribute(), job.primary_attribute(), "{}", job);
+        assert_eq!(category.accessory_category().attribute(), job.primary_attribute(), "{}", job);
+    }
+}
+
+#[cfg(feature = "attributes")]
+#[test]
+fn healers_and_casters_deal_magical_damage() {
+    use ffxiv_types_cn::{Attribute, DamageType};
+
+    for &job in Job::ALL.iter() {
+        let expected = match job.primary_attribute() {
+            Attribute::Intelligence | Attribute::Mind => DamageType::Magical,
+            _ => DamageType::Physical,
+        };
+        assert_eq!(job.damage_type(), expected, "{}", job);
+    }
+}
+
+#[cfg(feature = "gear")]
+#[test]
+fn accessories_are_shared_between_melee_categories() {
+    use ffxiv_types_cn::GearCategory;
+
+    assert_eq!(Job::Dragoon.gear_category(), GearCategory::Maiming);
+    assert_eq!(Job::Monk.gear_category().accessory_category(), GearCategory::Slaying);
+    assert_eq!(Job::Ninja.gear_category().accessory_category(), GearCategory::Aiming);
+    assert_eq!(Job::Paladin.gear_category().accessory_category(), GearCategory::Fending);
+}
+
+#[test]
+fn base_classes_match_class_jobs() {
+    use ffxiv_types_cn::jobs::Class;
+
+    for &job in Job::ALL.iter() {
+        for &class in Class::ALL.iter() {
+            let listed = class.jobs().contains(&job);
+            assert_eq!(listed, job.base_class() == Some(class), "{} {}", class, job);
+        }
+    }
+}
+
+#[test]
+fn jobs_without_base_classes() {
+    let jobs = [
+        Job::Dancer,
+        Job::Gunbreaker,
+        Job::Sage,
+        Job::Viper,
+        Job::Pictomancer,
+        Job::Reaper,
+        Job::DarkKnight,
+        Job::Astrologian,
+        Job::Machinist,
+        Job::RedMage,
+        Job::BlueMage,
+    ];
+    for &job in jobs.iter() {
+        assert_eq!(job.base_class(), None, "{}", job);
+    }
+}
+
+#[test]
+fn arcanist_has_two_jobs() {
+    use ffxiv_types_cn::jobs::Class;
+
+    assert_eq!(Class::Arcanist.jobs(), &[Job::Summoner, Job::Scholar]);
+    assert_eq!(Job::Summoner.base_class(), Some(Class::Arcanist));
+    assert_eq!(Job::Scholar.base_class(), Some(Class::Arcanist));
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "levels")]
+
+extern crate ffxiv_types_cn;
+
+#[cfg(feature = "combat_jobs")]
+use ffxiv_types_cn::{Expansion, Job};
+#[cfg(any(feature = "combat_jobs", feature = "non_combat_jobs"))]
+use ffxiv_types_cn::GameVersion;
+use ffxiv_types_cn::Level;
+
+#[test]
+fn levels_are_in_range() {
+    assert_eq!(Level::new(0), None);
+    assert_eq!(Level::new(1), Some(Level::MIN));
+    assert_eq!(Level::new(100), Some(Level::MAX));
+    assert_eq!(Level::new(101), None);
+    assert_eq!(Level::MAX.exp_to_next(), None);
+}
+
+#[cfg(feature = "combat_jobs")]
+#[test]
+fn job_level_caps_follow_the_expansion() {
+    for &(major, minor) in &[(2, 0), (3, 5), (4, 0), (5, 58), (6, 0), (7, 0)] {
+        let version = GameVersion::new(major, minor);
+        let cap = Expansion::of(version).level_cap();
+        assert_eq!(Job::Paladin.max_level(version), Some(cap));
+    }
+
+    assert_eq!(Job::Paladin.max_level(GameVersion::new(6, 58)), Some(90));
+    assert_eq!(Job::Reaper.max_level(GameVersion::new(5, 58)), None);
+    assert_eq!(Job::BlueMage.max_level(GameVersion::new(6, 45)), Some(80));
+}
+
+#[cfg(feature = "combat_jobs")]
+#[test]
+fn job_levels_are_validated() {
+    let version = GameVersion::new(7, 0);
+    assert!(Level::for_job(Job::Viper, 80, version).is_ok());
+    assert!(Level::for_job(Job::Viper, 79, version).is_err());
+    assert!(Level::for_job(Job::BlueMage, 81, version).is_err());
+    assert!(Level::for_job(Job::Paladin, 90, GameVersion::new(5, 0)).is_err());
+}
+
+#[test]
+fn exp_totals() {
+    let level = |level| Level::new(level).unwrap();
+
+    assert_eq!(Level::MIN.exp_to_next(), Some(300));
+    assert_eq!(Level::exp_to_reach(level(1), level(50)), 7_734_000);
+    assert_eq!(Level::exp_to_reach(level(1), level(51)), 8_598_000);
+    assert_eq!(Level::exp_to_reach(level(30), level(50)), 6_416_320);
+    assert_eq!(Level::exp_to_reach(level(50), level(30)), 0);
+    assert_eq!(level(50).percent_to_next(432_000), Some(50.0));
+    assert_eq!(level(50).percent_to_next(863_999).map(|percent| percent < 100.0), Some(true));
+    assert_eq!(level(50).percent_to_next(864_000), None);
+    assert_eq!(level(50).percent_to_next(u32::MAX), None);
+}
+
+#[cfg(feature = "combat_jobs")]
+#[test]
+fn exp_tables_stop_at_the_level_cap() {
+    use ffxiv_types_cn::jobs::Class;
+    use ffxiv_types_cn::ExpTable;
+
+    let level = |level| Level::new(level).unwrap();
+    let version = GameVersion::new(7, 0);
+
+    let gladiator = ExpTable::new(Class::Gladiator, version).unwrap();
+    assert_eq!((gladiator.min_level(), gladiator.max_level()), (level(1), level(50)));
+    assert_eq!(gladiator.exp_to_next(level(49)), Some(529_000));
+    assert_eq!(gladiator.exp_to_next(level(50)), None);
+    assert_eq!(gladiator.exp_to_reach(level(1), level(50)), Some(7_734_000));
+    assert_eq!(gladiator.exp_to_reach(level(1), level(51)), None);
+
+    let paladin = ExpTable::new(Job::Paladin, version).unwrap();
+    assert_eq!((paladin.min_level(), paladin.max_level()), (level(30), level(100)));
+    assert_eq!(paladin.exp_to_next(level(29)), None);
+    assert_eq!(paladin.exp_to_reach(level(30), level(50)), Some(6_416_320));
+
+    let blue_mage = ExpTable::new(Job::BlueMage, GameVersion::new(6, 45)).unwrap();
+    assert_eq!(blue_mage.max_level(), level(80));
+    assert_eq!(blue_mage.exp_to_next(level(80)), None);
+    assert_eq!(blue_mage.percent_to_next(level(79), 0), Some(0.0));
+    let exp_to_next = blue_mage.exp_to_next(level(79)).unwrap();
+    assert_eq!(blue_mage.percent_to_next(level(79), exp_to_next), None);
+    assert_eq!(blue_mage.percent_to_next(level(80), 0), None);
+
+    assert!(ExpTable::new(Job::Viper, GameVersion::new(6, 58)).is_none());
+}
+
+#[cfg(feature = "non_combat_jobs")]
+#[test]
+fn non_combat_jobs_follow_the_expansion_cap() {
+    use ffxiv_types_cn::jobs::NonCombatJob;
+    use ffxiv_types_cn::ExpTable;
+
+    let miner = ExpTable::new(NonCombatJob::Miner, GameVersion::new(6, 0)).unwrap();
+    assert_eq!(miner.max_level(), Level::new(90).unwrap());
+}
@@ -0,0 +1,158 @@
+#![cfg(feature = "all_const")]
+
+extern crate ffxiv_types_cn;
+
+#[cfg(feature = "job_classifications")]
+use ffxiv_types_cn::jobs::Classification;
+#[cfg(feature = "combat_jobs")]
+use ffxiv_types_cn::jobs::{Class, Job};
+#[cfg(feature = "non_combat_jobs")]
+use ffxiv_types_cn::jobs::NonCombatJob;
+#[cfg(feature = "city_states")]
+use ffxiv_types_cn::CityState;
+#[cfg(feature = "clans")]
+use ffxiv_types_cn::Clan;
+#[cfg(feature = "expansions")]
+use ffxiv_types_cn::Expansion;
+#[cfg(feature = "gear")]
+use ffxiv_types_cn::GearCategory;
+#[cfg(feature = "genders")]
+use ffxiv_types_cn::Gender;
+#[cfg(feature = "guardians")]
+use ffxiv_types_cn::Guardian;
+use ffxiv_types_cn::Language;
+#[cfg(feature = "calendar")]
+use ffxiv_types_cn::Moon;
+#[cfg(feature = "races")]
+use ffxiv_types_cn::Race;
+#[cfg(feature = "roles")]
+use ffxiv_types_cn::SubRole;
+#[cfg(feature = "world_status")]
+use ffxiv_types_cn::WorldCategory;
+#[cfg(feature = "attributes")]
+use ffxiv_types_cn::{Attribute, DamageType};
+#[cfg(feature = "grand_companies")]
+use ffxiv_types_cn::{GrandCompany, GrandCompanyMembership, GrandCompanyRank};
+#[cfg(feature = "parties")]
+use ffxiv_types_cn::{PartyKind, RoleCategory};
+
+/// Checks that every name of every variant parses back to the variant in its language, and that
+/// the English name parses with `FromStr` regardless of case.
+macro_rules! round_trip {
+    ($test:ident, $ty:ident) => {
+        #[test]
+        fn $test() {
+            for &variant in $ty::ALL.iter() {
+                for &language in Language::ALL.iter() {
+                    let name = variant.name_in(language);
+                    assert_eq!($ty::from_str_in(name, &[language]).ok(), Some(variant), "{}", name);
+                }
+
+                let name = variant.name_in(Language::English).to_uppercase();
+                assert_eq!(name.parse::<$ty>().ok(), Some(variant), "{}", name);
+            }
+
+            assert!($ty::from_str_in("not a name", &Language::ALL).is_err());
+        }
+    };
+}
+
+/// Checks that every abbreviation of every variant parses back to the variant in its language.
+macro_rules! abbreviations_round_trip {
+    ($test:ident, $ty:ident) => {
+        #[test]
+        fn $test() {
+            for &variant in $ty::ALL.iter() {
+                for &language in Language::ALL.iter() {
+                    for &abbreviation in variant.abbreviations_in(language) {
+                        let parsed = $ty::from_str_in(abbreviation, &[language]).ok();
+                        assert_eq!(parsed, Some(variant), "{}", abbreviation);
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "combat_jobs")]
+round_trip!(job_names, Job);
+#[cfg(feature = "combat_jobs")]
+abbreviations_round_trip!(job_abbreviations, Job);
+#[cfg(feature = "combat_jobs")]
+round_trip!(class_names, Class);
+#[cfg(feature = "non_combat_jobs")]
+round_trip!(non_combat_job_names, NonCombatJob);
+#[cfg(feature = "non_combat_jobs")]
+abbreviations_round_trip!(non_combat_job_abbreviations, NonCombatJob);
+#[cfg(feature = "job_classifications")]
+round_trip!(classification_names, Classification);
+#[cfg(feature = "races")]
+round_trip!(race_names, Race);
+#[cfg(feature = "races")]
+abbreviations_round_trip!(race_abbreviations, Race);
+#[cfg(feature = "clans")]
+round_trip!(clan_names, Clan);
+#[cfg(feature = "guardians")]
+round_trip!(guardian_names, Guardian);
+#[cfg(feature = "world_status")]
+round_trip!(world_category_names, WorldCategory);
+#[cfg(feature = "calendar")]
+round_trip!(moon_names, Moon);
+
+#[cfg(feature = "combat_jobs")]
+#[test]
+fn languages_are_tried_in_order() {
+    let black_mage = Job::from_str_in("黑魔", &[Language::ChineseSimplified]).ok();
+    assert_eq!(black_mage, Some(Job::BlackMage));
+    assert!(Job::from_str_in("黑魔", &[Language::English]).is_err());
+    assert_eq!("Schwarzmagier".parse::<Job>().ok(), Some(Job::BlackMage));
+}
+#[cfg(feature = "genders")]
+round_trip!(gender_names, Gender);
+#[cfg(feature = "city_states")]
+round_trip!(city_state_names, CityState);
+#[cfg(feature = "city_states")]
+abbreviations_round_trip!(city_state_abbreviations, CityState);
+#[cfg(feature = "grand_companies")]
+round_trip!(grand_company_names, GrandCompany);
+#[cfg(feature = "grand_companies")]
+abbreviations_round_trip!(grand_company_abbreviations, GrandCompany);
+#[cfg(feature = "grand_companies")]
+round_trip!(grand_company_rank_names, GrandCompanyRank);
+
+#[cfg(feature = "grand_companies")]
+#[test]
+fn grand_company_memberships_round_trip() {
+    for &grand_company in GrandCompany::ALL.iter() {
+        for &rank in GrandCompanyRank::ALL.iter() {
+            let membership = GrandCompanyMembership {
+                grand_company,
+                rank,
+            };
+
+            for &language in Language::ALL.iter() {
+                let name = grand_company.name_in(language);
+                let line = format!("{}/{}", name, membership.title_in(language));
+                assert_eq!(line.parse().ok(), Some(membership), "{}", line);
+            }
+        }
+    }
+
+    assert!("Maelstrom".parse::<GrandCompanyMembership>().is_err());
+}
+#[cfg(feature = "expansions")]
+round_trip!(expansion_names, Expansion);
+#[cfg(feature = "roles")]
+round_trip!(sub_role_names, SubRole);
+#[cfg(feature = "roles")]
+abbreviations_round_trip!(sub_role_abbreviations, SubRole);
+#[cfg(feature = "parties")]
+round_trip!(party_kind_names, PartyKind);
+#[cfg(feature = "parties")]
+round_trip!(role_category_names, RoleCategory);
+#[cfg(feature = "attributes")]
+round_trip!(attribute_names, Attribute);
+#[cfg(feature = "attributes")]
+round_trip!(damage_type_names, DamageType);
+#[cfg(feature = "gear")]
+round_trip!(gear_category_names, GearCategory);
@@ -0,0 +1,195 @@
+#![cfg(feature = "parties")]
+
+extern crate ffxiv_types_cn;
+
+use ffxiv_types_cn::jobs::Job;
+use ffxiv_types_cn::{
+    Composition, CompositionIssue, NoAssignment, Party, PartyKind, Player, Role, SubRole,
+};
+
+#[test]
+fn parties_do_not_overflow() {
+    let mut party = Party::new(PartyKind::Light);
+    for &job in &[Job::Paladin, Job::WhiteMage, Job::Ninja, Job::BlackMage] {
+        assert!(party.push(job));
+    }
+    assert!(party.is_full());
+    assert!(!party.push(Job::Bard));
+    assert_eq!(party.len(), 4);
+
+    assert!(Party::with_jobs(PartyKind::Light, &[Job::Bard; 5]).is_none());
+    assert!(Party::with_jobs(PartyKind::Alliance, &[Job::Bard; 24]).is_some());
+}
+
+#[test]
+fn standard_full_parties_are_checked() {
+    let jobs = [
+        Job::Paladin,
+        Job::Warrior,
+        Job::WhiteMage,
+        Job::Scholar,
+        Job::Ninja,
+        Job::Bard,
+        Job::BlackMage,
+        Job::Samurai,
+    ];
+    let party = Party::with_jobs(PartyKind::Full, &jobs).unwrap();
+    assert!(party.satisfies(&Composition::STANDARD_FULL_PARTY));
+
+    let jobs = [Job::Paladin, Job::WhiteMage, Job::Ninja, Job::Ninja];
+    let party = Party::with_jobs(PartyKind::Full, &jobs).unwrap();
+    assert_eq!(
+        party.check(&Composition::STANDARD_FULL_PARTY),
+        vec![
+            CompositionIssue::MissingRole(Role::Tank, 1),
+            CompositionIssue::MissingRole(Role::Healer, 1),
+            CompositionIssue::MissingRole(Role::Dps, 2),
+            CompositionIssue::MissingSubRole(SubRole::PhysicalRanged, 1),
+            CompositionIssue::MissingSubRole(SubRole::MagicalRanged, 1),
+            CompositionIssue::DuplicateJob(Job::Ninja, 2),
+        ]
+    );
+}
+
+#[test]
+fn compositions_for_other_sizes_are_rejected() {
+    let jobs = [Job::Paladin, Job::WhiteMage, Job::Ninja, Job::Bard];
+    let party = Party::with_jobs(PartyKind::Full, &jobs).unwrap();
+    assert_eq!(
+        party.check(&Composition::LIGHT_PARTY),
+        vec![CompositionIssue::SizeMismatch(4, 8)]
+    );
+
+    for &kind in &[PartyKind::Light, PartyKind::Full, PartyKind::Alliance] {
+        assert_eq!(Composition::for_kind(kind).size(), kind.size());
+    }
+}
+
+#[test]
+fn assignments_give_players_their_preferred_jobs() {
+    let players = [
+        Player::new("a", &[Job::WhiteMage, Job::Paladin]),
+        Player::new("b", &[Job::Paladin, Job::Ninja]),
+        Player::new("c", &[Job::Ninja, Job::Scholar]),
+        Player::new("d", &[Job::Bard]),
+    ];
+
+    let assignment = Composition::LIGHT_PARTY.assign(&players).unwrap();
+    let expected = vec![(0, Job::WhiteMage), (1, Job::Paladin), (2, Job::Ninja), (3, Job::Bard)];
+    assert_eq!(assignment.slots, expected);
+    assert_eq!(assignment.cost, 0);
+    assert!(assignment.party(PartyKind::Light).unwrap().satisfies(&Composition::LIGHT_PARTY));
+    assert_eq!(Composition::LIGHT_PARTY.assign(&players).unwrap(), assignment);
+}
+
+#[test]
+fn assignments_move_players_down_their_preferences_when_needed() {
+    let players = [
+        Player::new("a", &[Job::Ninja, Job::Paladin]),
+        Player::new("b", &[Job::Ninja, Job::WhiteMage]),
+        Player::new("c", &[Job::Ninja]),
+        Player::new("d", &[Job::Bard]),
+    ];
+
+    let assignment = Composition::LIGHT_PARTY.assign(&players).unwrap();
+    let expected = vec![(0, Job::Paladin), (1, Job::WhiteMage), (2, Job::Ninja), (3, Job::Bard)];
+    assert_eq!(assignment.slots, expected);
+    assert_eq!(assignment.cost, 2);
+}
+
+#[test]
+fn leaving_players_out_has_a_cost() {
+    let players = [
+        Player::new("a", &[Job::Paladin]),
+        Player::new("b", &[Job::Warrior, Job::WhiteMage]),
+        Player::new("c", &[Job::WhiteMage]),
+        Player::new("d", &[Job::Ninja]),
+        Player::new("e", &[Job::Bard]),
+    ];
+
+    // Leaving "a" out costs 1, and leaving "b" out costs 2.
+    let assignment = Composition::LIGHT_PARTY.assign(&players).unwrap();
+    let expected = vec![(1, Job::Warrior), (2, Job::WhiteMage), (3, Job::Ninja), (4, Job::Bard)];
+    assert_eq!(assignment.slots, expected);
+    assert_eq!(assignment.cost, 1);
+}
+
+#[test]
+fn assignments_explain_failures() {
+    let dps = Player::new("dps", &[Job::Ninja]);
+    let tank = Player::new("tank", &[Job::Paladin]);
+
+    match Composition::LIGHT_PARTY.assign(&[dps.clone(), tank.clone()]) {
+        Err(NoAssignment::NotEnoughPlayers(4, 2)) => {}
+        other => panic!("{:?}", other),
+    }
+    match Composition::LIGHT_PARTY.assign(&[dps.clone(), dps.clone(), dps.clone(), tank.clone()]) {
+        Err(NoAssignment::NotEnoughRole(Role::Healer, 1, 0)) => {}
+        other => panic!("{:?}", other),
+    }
+
+    let both = Player::new("both", &[Job::Paladin, Job::WhiteMage]);
+    match Composition::LIGHT_PARTY.assign(&[both, dps.clone(), dps.clone(), dps.clone()]) {
+        Err(NoAssignment::NotEnoughRole(Role::Tank, 1, 1)) | Err(NoAssignment::Conflict) => {}
+        other => panic!("{:?}", other),
+    }
+
+    let healer = Player::new("healer", &[Job::WhiteMage]);
+    let composition = Composition {
+        allow_duplicate_jobs: false,
+        ..Composition::LIGHT_PARTY
+    };
+    match composition.assign(&[tank, healer, dps.clone(), dps]) {
+        Err(NoAssignment::Conflict) => {}
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn assignments_handle_many_players() {
+    let mut players = vec![Player::new("dps", &[Job::Ninja, Job::Bard]); 300];
+    players.push(Player::new("tank", &[Job::Paladin]));
+    players.push(Player::new("healer", &[Job::WhiteMage]));
+
+    let assignment = Composition::LIGHT_PARTY.assign(&players).unwrap();
+    let expected = vec![
+        (0, Job::Ninja),
+        (1, Job::Ninja),
+        (300, Job::Paladin),
+        (301, Job::WhiteMage),
+    ];
+    assert_eq!(assignment.slots, expected);
+    assert_eq!(assignment.cost, 298 * 2);
+
+    match Composition::FULL_PARTY.assign(&players) {
+        Err(NoAssignment::NotEnoughRole(Role::Tank, 2, 1)) => {}
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn party_bonuses_count_distinct_categories() {
+    use ffxiv_types_cn::jobs::Class;
+    use ffxiv_types_cn::{ClassJob, PartyBonus, RoleCategory};
+
+    let bonus = PartyBonus::of(vec![Job::Paladin, Job::Warrior, Job::Ninja, Job::Samurai]);
+    assert_eq!(bonus.percent(), 2);
+    assert_eq!(bonus.covered(), vec![RoleCategory::Tank, RoleCategory::Melee]);
+    assert!(!bonus.covers(RoleCategory::Healer));
+
+    let members = [
+        ClassJob::Class(Class::Gladiator),
+        ClassJob::Job(Job::Scholar),
+        ClassJob::Class(Class::Lancer),
+        ClassJob::Job(Job::Dancer),
+        ClassJob::Job(Job::Pictomancer),
+    ];
+    let bonus = PartyBonus::of(members.iter().cloned());
+    assert_eq!(bonus.percent(), 5);
+    assert!(bonus.missing().is_empty());
+    assert_eq!(bonus.apply(1000), 1050);
+    assert_eq!(bonus.apply(u32::MAX), u32::MAX);
+
+    assert_eq!(PartyBonus::of(Vec::<Job>::new()), PartyBonus::default());
+    assert_eq!(PartyBonus::default().apply(1000), 1000);
+}
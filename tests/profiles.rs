@@ -0,0 +1,61 @@
+#![cfg(feature = "profiles")]
+
+extern crate ffxiv_types_cn;
+
+use ffxiv_types_cn::jobs::Class;
+use ffxiv_types_cn::{
+    CharacterProfile, CityState, Clan, GameVersion, Gender, Guardian, InvalidProfile, Moon,
+    Nameday, Race,
+};
+
+fn profile(
+    race: Race,
+    clan: Clan,
+    gender: Gender,
+    class: Class,
+    created_in: GameVersion,
+) -> Result<CharacterProfile, InvalidProfile> {
+    let nameday = Nameday::new(Moon::FirstAstral, 1).unwrap();
+    CharacterProfile::new(race, clan, gender, nameday, Guardian::Halone, class, created_in)
+}
+
+#[test]
+fn valid_profiles_start_in_the_class_city_state() {
+    let archer = profile(
+        Race::Miqote,
+        Clan::SeekerOfTheSun,
+        Gender::Female,
+        Class::Archer,
+        GameVersion::new(2, 0),
+    )
+    .unwrap();
+    assert_eq!(archer.city_state(), CityState::Gridania);
+
+    let hrothgar = profile(
+        Race::Hrothgar,
+        Clan::Helions,
+        Gender::Female,
+        Class::Marauder,
+        GameVersion::new(7, 0),
+    )
+    .unwrap();
+    assert_eq!(hrothgar.city_state(), CityState::LimsaLominsa);
+}
+
+#[test]
+fn invalid_profiles_are_rejected() {
+    let version = GameVersion::new(6, 0);
+
+    match profile(Race::Miqote, Clan::Rava, Gender::Female, Class::Archer, version) {
+        Err(InvalidProfile::ClanMismatch(Race::Miqote, Clan::Rava)) => {}
+        other => panic!("{:?}", other),
+    }
+    match profile(Race::Hrothgar, Clan::Helions, Gender::Female, Class::Archer, version) {
+        Err(InvalidProfile::GenderUnavailable(Race::Hrothgar, Gender::Female, _)) => {}
+        other => panic!("{:?}", other),
+    }
+    match profile(Race::Miqote, Clan::SeekerOfTheSun, Gender::Female, Class::Rogue, version) {
+        Err(InvalidProfile::NotStartingClass(Class::Rogue)) => {}
+        other => panic!("{:?}", other),
+    }
+}
@@ -0,0 +1,55 @@
+#![cfg(all(feature = "regions", feature = "all_const"))]
+
+extern crate ffxiv_types_cn;
+
+use ffxiv_types_cn::{DataCenter, Publisher, Region};
+
+#[test]
+fn data_centers_are_listed_by_their_region() {
+    for &data_center in DataCenter::ALL.iter() {
+        assert!(data_center.region().data_centers().contains(&data_center), "{}", data_center);
+    }
+
+    for &region in Region::ALL.iter() {
+        for data_center in region.data_centers() {
+            assert_eq!(data_center.region(), region, "{}", data_center);
+        }
+    }
+
+    let total: usize = Region::ALL.iter().map(|region| region.data_centers().len()).sum();
+    assert_eq!(total, DataCenter::ALL.len());
+}
+
+#[test]
+fn regions_are_listed_by_their_publisher() {
+    for &region in Region::ALL.iter() {
+        assert!(region.publisher().regions().contains(&region), "{}", region);
+    }
+
+    for &publisher in Publisher::ALL.iter() {
+        for region in publisher.regions() {
+            assert_eq!(region.publisher(), publisher, "{}", region);
+        }
+    }
+}
+
+#[test]
+fn publishers_and_locations() {
+    let expected = [
+        (Region::NorthAmerica, Publisher::SquareEnix, "Sacramento"),
+        (Region::Europe, Publisher::SquareEnix, "London"),
+        (Region::Japan, Publisher::SquareEnix, "Tokyo"),
+        (Region::Oceania, Publisher::SquareEnix, "Sydney"),
+        (Region::China, Publisher::Shanda, "Shanghai"),
+        (Region::Taiwan, Publisher::ChuanQi, "Taipei"),
+        (Region::Korea, Publisher::Actoz, "Seoul"),
+    ];
+    assert_eq!(expected.len(), Region::ALL.len());
+
+    for &(region, publisher, location) in expected.iter() {
+        assert_eq!(region.publisher(), publisher, "{}", region);
+        for data_center in region.data_centers() {
+            assert_eq!(data_center.location(), location, "{}", data_center);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "with_serde")]
+
+extern crate bincode;
+extern crate ffxiv_types_cn;
+extern crate serde_json;
+
+#[cfg(all(feature = "combat_jobs", feature = "non_combat_jobs"))]
+use ffxiv_types_cn::jobs::{Class, Job, NonCombatJob};
+#[cfg(all(feature = "combat_jobs", feature = "non_combat_jobs"))]
+use ffxiv_types_cn::ClassJob;
+
+#[cfg(all(feature = "combat_jobs", feature = "non_combat_jobs"))]
+#[test]
+fn class_job_round_trips() {
+    let class_jobs = [
+        ClassJob::Class(Class::Gladiator),
+        ClassJob::Job(Job::Paladin),
+        ClassJob::NonCombat(NonCombatJob::Miner),
+    ];
+
+    for &class_job in class_jobs.iter() {
+        let json = serde_json::to_string(&class_job).unwrap();
+        assert_eq!(json, class_job.id().to_string());
+        assert_eq!(serde_json::from_str::<ClassJob>(&json).unwrap(), class_job);
+
+        let bytes = bincode::serialize(&class_job).unwrap();
+        assert_eq!(bincode::deserialize::<ClassJob>(&bytes).unwrap(), class_job);
+    }
+}
+
+#[cfg(all(feature = "combat_jobs", feature = "non_combat_jobs"))]
+#[test]
+fn class_job_deserializes_from_names_in_json() {
+    let paladin: ClassJob = serde_json::from_str("\"PLD\"").unwrap();
+    assert_eq!(paladin, ClassJob::Job(Job::Paladin));
+    assert!(serde_json::from_str::<ClassJob>("9999").is_err());
+}
+
+#[cfg(feature = "world_status")]
+#[test]
+fn world_status_table_is_a_map() {
+    use ffxiv_types_cn::{World, WorldCategory, WorldStatus, WorldStatusTable};
+
+    let json = r#"{"Adamantoise": {"category": "Standard", "character_creation_allowed": true}}"#;
+    let table: WorldStatusTable = serde_json::from_str(json).unwrap();
+    assert_eq!(table.get(World::Adamantoise), WorldStatus::new(WorldCategory::Standard));
+
+    let snapshot = WorldStatusTable::snapshot();
+    let json = serde_json::to_string(&snapshot).unwrap();
+    assert_eq!(serde_json::from_str::<WorldStatusTable>(&json).unwrap(), snapshot);
+}
+
+#[cfg(feature = "calendar")]
+#[test]
+fn nameday_deserialization_checks_the_sun() {
+    use ffxiv_types_cn::{Moon, Nameday};
+
+    let nameday = Nameday::new(Moon::SixthUmbral, 32).unwrap();
+    let json = serde_json::to_string(&nameday).unwrap();
+    assert_eq!(serde_json::from_str::<Nameday>(&json).unwrap(), nameday);
+
+    for sun in &[0, 33, 255] {
+        let json = format!(r#"{{"moon": "FirstAstral", "sun": {}}}"#, sun);
+        assert!(serde_json::from_str::<Nameday>(&json).is_err(), "{}", sun);
+    }
+}
+
+#[cfg(feature = "profiles")]
+#[test]
+fn profile_deserialization_checks_the_nameday() {
+    use ffxiv_types_cn::jobs::Class;
+    use ffxiv_types_cn::{
+        CharacterProfile, Clan, GameVersion, Gender, Guardian, Moon, Nameday, Race,
+    };
+
+    let profile = CharacterProfile::new(
+        Race::Miqote,
+        Clan::SeekerOfTheSun,
+        Gender::Female,
+        Nameday::new(Moon::FirstAstral, 1).unwrap(),
+        Guardian::Halone,
+        Class::Archer,
+        GameVersion::new(2, 0),
+    )
+    .unwrap();
+
+    let mut json = serde_json::to_value(profile).unwrap();
+    assert_eq!(serde_json::from_value::<CharacterProfile>(json.clone()).unwrap(), profile);
+
+    json["nameday"]["sun"] = 0.into();
+    assert!(serde_json::from_value::<CharacterProfile>(json).is_err());
+}
+
+#[cfg(feature = "levels")]
+#[test]
+fn level_deserialization_checks_the_range() {
+    use ffxiv_types_cn::Level;
+
+    assert_eq!(serde_json::to_string(&Level::MAX).unwrap(), "100");
+    assert_eq!(serde_json::from_str::<Level>("1").ok(), Level::new(1));
+    assert_eq!(serde_json::from_str::<Level>("100").ok(), Some(Level::MAX));
+    assert!(serde_json::from_str::<Level>("0").is_err());
+    assert!(serde_json::from_str::<Level>("101").is_err());
+
+    let bytes = bincode::serialize(&0u8).unwrap();
+    assert!(bincode::deserialize::<Level>(&bytes).is_err());
+}
+
+#[cfg(feature = "parties")]
+#[test]
+fn party_deserialization_checks_the_size() {
+    use ffxiv_types_cn::jobs::Job;
+    use ffxiv_types_cn::{Party, PartyKind};
+
+    let party = Party::with_jobs(PartyKind::Light, &[Job::Paladin, Job::WhiteMage]).unwrap();
+    let json = serde_json::to_string(&party).unwrap();
+    assert_eq!(serde_json::from_str::<Party>(&json).unwrap(), party);
+
+    let jobs = vec![Job::Bard; 300];
+    let json = serde_json::json!({ "kind": "Light", "jobs": jobs });
+    assert!(serde_json::from_value::<Party>(json).is_err());
+}
@@ -0,0 +1,67 @@
+#![cfg(all(feature = "worlds", feature = "regions"))]
+
+extern crate ffxiv_types_cn;
+
+use ffxiv_types_cn::{TravelKind, World};
+
+#[test]
+fn same_world() {
+    assert_eq!(World::Adamantoise.can_travel_to(&World::Adamantoise), TravelKind::SameWorld);
+    assert_eq!(World::拂晓之间.can_travel_to(&World::拂晓之间), TravelKind::SameWorld);
+    assert!(TravelKind::SameWorld.is_possible());
+}
+
+#[test]
+fn world_visit_within_a_data_center() {
+    assert_eq!(World::Adamantoise.can_travel_to(&World::Cactuar), TravelKind::WorldVisit);
+    assert_eq!(World::晨曦王座.can_travel_to(&World::红玉海), TravelKind::WorldVisit);
+    assert_eq!(World::伊弗利特.can_travel_to(&World::迦樓羅), TravelKind::WorldVisit);
+    assert_eq!(World::펜리르.can_travel_to(&World::모그리), TravelKind::WorldVisit);
+}
+
+#[test]
+fn data_center_travel_within_a_region() {
+    assert_eq!(World::Adamantoise.can_travel_to(&World::Balmung), TravelKind::DataCenterTravel);
+    assert_eq!(World::Aegis.can_travel_to(&World::Alexander), TravelKind::DataCenterTravel);
+    // 超域旅行
+    assert_eq!(World::晨曦王座.can_travel_to(&World::拂晓之间), TravelKind::DataCenterTravel);
+    assert_eq!(World::琥珀原.can_travel_to(&World::红茶川), TravelKind::DataCenterTravel);
+}
+
+#[test]
+fn region_travel_on_the_global_service() {
+    assert_eq!(World::Adamantoise.can_travel_to(&World::Cerberus), TravelKind::RegionTravel);
+    assert_eq!(World::Cerberus.can_travel_to(&World::Adamantoise), TravelKind::RegionTravel);
+    assert_eq!(World::Aegis.can_travel_to(&World::Bismarck), TravelKind::RegionTravel);
+    assert!(TravelKind::RegionTravel.is_possible());
+}
+
+#[test]
+fn travel_between_publishers_is_impossible() {
+    assert_eq!(World::伊弗利特.can_travel_to(&World::拂晓之间), TravelKind::Impossible);
+    assert_eq!(World::拂晓之间.can_travel_to(&World::伊弗利特), TravelKind::Impossible);
+    assert_eq!(World::펜리르.can_travel_to(&World::Adamantoise), TravelKind::Impossible);
+    assert_eq!(World::Adamantoise.can_travel_to(&World::펜리르), TravelKind::Impossible);
+    assert!(!TravelKind::Impossible.is_possible());
+}
+
+#[cfg(feature = "all_const")]
+#[test]
+fn travel_follows_publishers() {
+    for &from in World::ALL.iter() {
+        for &to in World::ALL.iter() {
+            let kind = from.can_travel_to(&to);
+            let (from_region, to_region) = (from.data_center().region(), to.data_center().region());
+
+            assert_eq!(kind, to.can_travel_to(&from), "{} {}", from, to);
+            assert_eq!(kind.is_possible(), from_region.publisher() == to_region.publisher());
+            assert_eq!(
+                kind == TravelKind::RegionTravel,
+                from_region != to_region && from_region.publisher() == to_region.publisher(),
+                "{} {}",
+                from,
+                to
+            );
+        }
+    }
+}
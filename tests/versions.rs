@@ -0,0 +1,69 @@
+extern crate ffxiv_types_cn;
+
+#[cfg(feature = "data_centers")]
+use ffxiv_types_cn::DataCenter;
+use ffxiv_types_cn::GameVersion;
+#[cfg(all(feature = "worlds", feature = "data_centers"))]
+use ffxiv_types_cn::World;
+
+#[test]
+fn game_version_round_trips() {
+    for &(s, major, minor) in &[("6.18", 6, 18), ("6.1", 6, 10), ("6.05", 6, 5), ("7.0", 7, 0)] {
+        let version: GameVersion = s.parse().unwrap();
+        assert_eq!(version, GameVersion::new(major, minor));
+        assert_eq!(version.to_string(), s);
+    }
+
+    assert_eq!("7".parse::<GameVersion>().ok(), Some(GameVersion::new(7, 0)));
+    for s in &["", "6.", "6.123", "x.1", "6.1.1"] {
+        assert!(s.parse::<GameVersion>().is_err(), "{}", s);
+    }
+}
+
+#[test]
+fn game_versions_compare_in_release_order() {
+    assert!(GameVersion::new(6, 5) < GameVersion::new(6, 10));
+    assert!(GameVersion::new(6, 58) < GameVersion::new(7, 0));
+}
+
+#[cfg(all(feature = "worlds", feature = "data_centers"))]
+#[test]
+fn data_center_at_follows_moves() {
+    let ramuh = |major, minor| World::Ramuh.data_center_at(GameVersion::new(major, minor));
+    assert_eq!(ramuh(6, 10), Some(DataCenter::Elemental));
+    assert_eq!(ramuh(6, 18), Some(DataCenter::Meteor));
+    assert_eq!(ramuh(7, 0), Some(DataCenter::Meteor));
+    assert_eq!(World::Bismarck.data_center_at(GameVersion::new(6, 8)), Some(DataCenter::Materia));
+}
+
+#[cfg(all(feature = "worlds", feature = "data_centers"))]
+#[test]
+fn data_center_at_before_a_world_opened_is_none() {
+    assert_eq!(World::Alpha.data_center_at(GameVersion::new(2, 0)), None);
+    assert_eq!(World::Alpha.data_center_at(GameVersion::new(6, 15)), None);
+    assert_eq!(World::Alpha.data_center_at(GameVersion::new(6, 18)), Some(DataCenter::Light));
+    assert_eq!(World::Bismarck.data_center_at(GameVersion::new(6, 5)), None);
+}
+
+#[cfg(all(feature = "worlds", feature = "data_centers"))]
+#[test]
+fn data_center_at_without_history_is_none() {
+    assert_eq!(World::Adamantoise.data_center_at(GameVersion::new(2, 0)), None);
+    assert_eq!(World::Adamantoise.data_center_at(GameVersion::new(7, 0)), None);
+}
+
+#[cfg(feature = "expansions")]
+#[test]
+fn patches_belong_to_the_latest_released_expansion() {
+    use ffxiv_types_cn::Expansion;
+
+    assert_eq!(Expansion::of(GameVersion::new(1, 23)), Expansion::ARealmReborn);
+    assert_eq!(Expansion::of(GameVersion::new(2, 58)), Expansion::ARealmReborn);
+    assert_eq!(Expansion::of(GameVersion::new(3, 0)), Expansion::Heavensward);
+    assert_eq!(Expansion::of(GameVersion::new(4, 5)), Expansion::Stormblood);
+    assert_eq!(Expansion::of(GameVersion::new(7, 2)), Expansion::Dawntrail);
+
+    assert_eq!(Expansion::Shadowbringers.version(), GameVersion::new(5, 0));
+    assert_eq!("ShB".parse::<Expansion>().ok(), Some(Expansion::Shadowbringers));
+    assert_eq!("a realm reborn".parse::<Expansion>().ok(), Some(Expansion::ARealmReborn));
+}
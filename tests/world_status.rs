@@ -0,0 +1,31 @@
+#![cfg(all(feature = "world_status", feature = "all_const"))]
+
+extern crate ffxiv_types_cn;
+
+use ffxiv_types_cn::{World, WorldCategory, WorldStatus, WorldStatusTable};
+
+#[test]
+fn snapshot_matches_world_status() {
+    let snapshot = WorldStatusTable::snapshot();
+    for &world in World::ALL.iter() {
+        assert_eq!(snapshot.get(world), world.status(), "{}", world);
+    }
+
+    assert!(!snapshot.get(World::Gilgamesh).character_creation_allowed);
+    assert_eq!(snapshot.get(World::Gilgamesh).category, WorldCategory::Congested);
+}
+
+#[test]
+fn overrides_replace_snapshot_entries() {
+    let mut table = WorldStatusTable::snapshot();
+    let standard = WorldStatus::new(WorldCategory::Standard);
+
+    let mut overrides = WorldStatusTable::new();
+    overrides.set(World::Gilgamesh, standard);
+    table.extend(overrides);
+
+    assert_eq!(table.get(World::Gilgamesh), standard);
+    assert!(!table.worlds_in(WorldCategory::Congested).contains(&World::Gilgamesh));
+    assert!(table.worlds_in(WorldCategory::Congested).contains(&World::Adamantoise));
+    assert_eq!(WorldStatusTable::new().worlds_in(WorldCategory::Standard).len(), World::ALL.len());
+}
@@ -0,0 +1,42 @@
+#![cfg(all(feature = "worlds", feature = "data_centers", feature = "all_const"))]
+
+extern crate ffxiv_types_cn;
+
+use ffxiv_types_cn::{DataCenter, World};
+
+#[test]
+fn worlds_are_listed_by_their_data_center() {
+    for &world in World::ALL.iter() {
+        assert!(world.data_center().worlds().contains(&world), "{}", world);
+    }
+
+    for &data_center in DataCenter::ALL.iter() {
+        for world in data_center.worlds() {
+            assert_eq!(world.data_center(), data_center, "{}", world);
+        }
+    }
+
+    let total: usize = DataCenter::ALL.iter().map(|data_center| data_center.worlds().len()).sum();
+    assert_eq!(total, 131);
+    assert_eq!(total, World::ALL.len());
+}
+
+#[cfg(feature = "regions")]
+#[test]
+fn worlds_are_listed_by_their_region() {
+    use ffxiv_types_cn::Region;
+
+    for &world in World::ALL.iter() {
+        let region = world.data_center().region();
+        assert!(region.worlds().any(|w| w == world), "{}", world);
+    }
+
+    for &region in Region::ALL.iter() {
+        for world in region.worlds() {
+            assert_eq!(world.data_center().region(), region, "{}", world);
+        }
+    }
+
+    let total: usize = Region::ALL.iter().map(|region| region.worlds().count()).sum();
+    assert_eq!(total, 131);
+}